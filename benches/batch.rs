@@ -0,0 +1,45 @@
+#[macro_use]
+extern crate bencher;
+
+use bencher::Bencher;
+use divisible_by::{count_divisible_by, divisible_by_3, divisible_by_3_all, divisible_by_bits};
+
+fn get_ns() -> Vec<u64> {
+    (0..4096).map(|i| 285889432707005401 + 7 * i).collect()
+}
+
+fn divisible_by_3_one_at_a_time(bench: &mut Bencher) {
+    let ns = get_ns();
+    let mut out = vec![false; ns.len()];
+    bench.iter(|| {
+        for (n, out) in ns.iter().zip(out.iter_mut()) {
+            *out = divisible_by_3(*n);
+        }
+    })
+}
+
+fn divisible_by_3_all_at_once(bench: &mut Bencher) {
+    let ns = get_ns();
+    let mut out = vec![false; ns.len()];
+    bench.iter(|| divisible_by_3_all(&ns, &mut out))
+}
+
+fn divisible_by_3_bits(bench: &mut Bencher) {
+    let ns = get_ns();
+    let mut bits = vec![0; ns.len().div_ceil(64)];
+    bench.iter(|| divisible_by_bits(&ns, 3, &mut bits))
+}
+
+fn count_divisible_by_3(bench: &mut Bencher) {
+    let ns = get_ns();
+    bench.iter(|| count_divisible_by(&ns, 3))
+}
+
+benchmark_group!(
+    benches,
+    divisible_by_3_one_at_a_time,
+    divisible_by_3_all_at_once,
+    divisible_by_3_bits,
+    count_divisible_by_3,
+);
+benchmark_main!(benches);
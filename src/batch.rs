@@ -0,0 +1,545 @@
+//! Divisibility checks for many numbers at once.
+//!
+//! Checking numbers one at a time with their digits does not let the compiler
+//! process several numbers together. The functions in this module instead use
+//! a kernel without branches or allocations that works on the binary
+//! representation of a number, so that the loops over a slice can be
+//! vectorised. Like the osculator of [`divisible_by_7`] is the inverse of ten
+//! (10) modulo seven, the kernel multiplies by the inverse of the odd part of
+//! the divisor modulo 2^64. Multiples of the divisor are then exactly the
+//! products that are small enough, see [`Kernel`].
+//!
+//! [`divisible_by_7`]: crate::divisible_by_7
+
+/// The number of numbers packed into a single word by [`divisible_by_bits`].
+const LANES: usize = u64::BITS as usize;
+
+/// A branch-free check for divisibility by a fixed divisor.
+///
+/// For an odd divisor `d` with inverse `i` modulo 2^64, the multiples `k * d`
+/// are mapped to `k` by `n * i`, so exactly the products up to `u64::MAX / d`
+/// belong to multiples. An even divisor `d * 2^s` additionally requires the
+/// lowest `s` bits of the product to be zero, which the rotation moves to the
+/// top where they make the product too large.
+#[derive(Clone, Copy, Debug)]
+struct Kernel {
+    inverse: u64,
+    shift: u32,
+    limit: u64,
+    never: bool,
+}
+
+impl Kernel {
+    fn new(d: u64) -> Self {
+        if d == 0 {
+            return Self {
+                inverse: 0,
+                shift: 0,
+                limit: 0,
+                never: true,
+            };
+        }
+
+        let shift = d.trailing_zeros();
+        let odd = d >> shift;
+        let inverse = (0..5).fold(odd, |inverse, _| {
+            inverse.wrapping_mul(2_u64.wrapping_sub(odd.wrapping_mul(inverse)))
+        });
+
+        Self {
+            inverse,
+            shift,
+            limit: u64::MAX / d,
+            never: false,
+        }
+    }
+
+    #[inline(always)]
+    fn divisible(self, n: u64) -> bool {
+        !self.never & (n.wrapping_mul(self.inverse).rotate_right(self.shift) <= self.limit)
+    }
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// zero (0), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_0_all};
+/// let mut out = [false; 4];
+/// divisible_by_0_all(&[0, 1, 2, 3], &mut out);
+/// assert_eq!(out, [false, false, false, false]);
+/// ```
+pub fn divisible_by_0_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 0, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// one (1), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_1_all};
+/// let mut out = [false; 4];
+/// divisible_by_1_all(&[0, 1, 2, 4], &mut out);
+/// assert_eq!(out, [true, true, true, true]);
+/// ```
+pub fn divisible_by_1_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 1, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// two (2), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_2_all};
+/// let mut out = [false; 4];
+/// divisible_by_2_all(&[0, 1, 4, 7], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_2_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 2, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// three (3), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_3_all};
+/// let mut out = [false; 4];
+/// divisible_by_3_all(&[0, 1, 6, 10], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_3_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 3, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// four (4), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_4_all};
+/// let mut out = [false; 4];
+/// divisible_by_4_all(&[0, 1, 8, 13], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_4_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 4, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// five (5), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_5_all};
+/// let mut out = [false; 4];
+/// divisible_by_5_all(&[0, 1, 10, 16], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_5_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 5, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// six (6), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_6_all};
+/// let mut out = [false; 4];
+/// divisible_by_6_all(&[0, 1, 12, 19], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_6_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 6, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// seven (7), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_7_all};
+/// let mut out = [false; 4];
+/// divisible_by_7_all(&[0, 1, 14, 22], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_7_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 7, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// eight (8), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_8_all};
+/// let mut out = [false; 4];
+/// divisible_by_8_all(&[0, 1, 16, 25], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_8_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 8, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// nine (9), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_9_all};
+/// let mut out = [false; 4];
+/// divisible_by_9_all(&[0, 1, 18, 28], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_9_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 9, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// ten (10), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_10_all};
+/// let mut out = [false; 4];
+/// divisible_by_10_all(&[0, 1, 20, 31], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_10_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 10, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// eleven (11), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_11_all};
+/// let mut out = [false; 4];
+/// divisible_by_11_all(&[0, 1, 22, 34], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_11_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 11, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by
+/// twelve (12), see [`divisible_by_all`].
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_12_all};
+/// let mut out = [false; 4];
+/// divisible_by_12_all(&[0, 1, 24, 37], &mut out);
+/// assert_eq!(out, [true, false, true, false]);
+/// ```
+pub fn divisible_by_12_all(ns: &[u64], out: &mut [bool]) {
+    divisible_by_all(ns, 12, out);
+}
+
+/// Determines for each of the provided numbers if it is divisible by the
+/// provided divisor, writing the results to `out` in the same order.
+///
+/// # Panics
+///
+/// Panics if `out` does not have the same length as `ns`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_all};
+/// let mut out = [false; 4];
+/// divisible_by_all(&[1309, 1310, 0, 17], 17, &mut out);
+/// assert_eq!(out, [true, false, true, true]);
+/// ```
+pub fn divisible_by_all(ns: &[u64], d: u64, out: &mut [bool]) {
+    assert_eq!(
+        ns.len(),
+        out.len(),
+        "the output must have the same length as the input"
+    );
+
+    let kernel = Kernel::new(d);
+    for (n, out) in ns.iter().zip(out) {
+        *out = kernel.divisible(*n);
+    }
+}
+
+/// Determines for each of the provided numbers if it is divisible by the
+/// provided divisor, setting bit `i % 64` of `bits[i / 64]` exactly when the
+/// `i`-th number is. Bits beyond the last number are cleared.
+///
+/// # Panics
+///
+/// Panics if `bits` does not have one word for every 64 numbers, i.e. if its
+/// length is not `ns.len().div_ceil(64)`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_bits};
+/// let mut bits = [0; 1];
+/// divisible_by_bits(&[21, 22, 0, 49], 7, &mut bits);
+/// assert_eq!(bits, [0b1101]);
+/// ```
+pub fn divisible_by_bits(ns: &[u64], d: u64, bits: &mut [u64]) {
+    assert_eq!(
+        ns.len().div_ceil(LANES),
+        bits.len(),
+        "the output must have one word for every {LANES} numbers"
+    );
+
+    let kernel = Kernel::new(d);
+    for (ns, word) in ns.chunks(LANES).zip(bits) {
+        *word = ns.iter().enumerate().fold(0, |word, (i, n)| {
+            word | (u64::from(kernel.divisible(*n)) << i)
+        });
+    }
+}
+
+/// Returns how many of the provided numbers are divisible by the provided
+/// divisor.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{count_divisible_by};
+/// let ns: Vec<u64> = (1..=100).collect();
+/// assert_eq!(count_divisible_by(&ns, 7), 14);
+/// ```
+pub fn count_divisible_by(ns: &[u64], d: u64) -> usize {
+    let kernel = Kernel::new(d);
+    ns.iter().map(|n| usize::from(kernel.divisible(*n))).sum()
+}
+
+/// Returns the indices of the provided numbers that are divisible by the
+/// provided divisor, in ascending order.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{filter_divisible_by};
+/// let indices: Vec<usize> = filter_divisible_by(&[3, 4, 5, 6], 3).collect();
+/// assert_eq!(indices, [0, 3]);
+/// ```
+pub fn filter_divisible_by(ns: &[u64], d: u64) -> impl Iterator<Item = usize> + '_ {
+    let kernel = Kernel::new(d);
+    ns.iter()
+        .enumerate()
+        .filter(move |(_, n)| kernel.divisible(**n))
+        .map(|(i, _)| i)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(42, 0)]
+    #[case(u64::MAX, 2)]
+    #[case(u64::MAX - 1, u64::MAX)]
+    #[case(1 << 62, 1 << 63)]
+    #[case(3 << 61, 3 << 62)]
+    fn is_not_divisible_by_kernel(#[case] n: u64, #[case] d: u64) {
+        assert!(!Kernel::new(d).divisible(n));
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(0, u64::MAX)]
+    #[case(u64::MAX, 1)]
+    #[case(u64::MAX, u64::MAX)]
+    #[case(1 << 63, 1 << 63)]
+    #[case(3 << 62, 3 << 61)]
+    #[case(u64::MAX - 3, 12)]
+    fn is_divisible_by_kernel(#[case] n: u64, #[case] d: u64) {
+        assert!(Kernel::new(d).divisible(n));
+    }
+
+    #[proptest]
+    fn are_divisible_by_kernel_like_the_remainder(n: u64, d: u64) {
+        assert_eq!(Kernel::new(d).divisible(n), n.checked_rem(d) == Some(0));
+    }
+
+    #[proptest]
+    fn are_divisible_by_kernel_like_the_rules(n: u64, d: u8) {
+        assert_eq!(
+            Kernel::new(d.into()).divisible(n),
+            crate::divisible_by(n, d.into())
+        );
+    }
+
+    #[proptest]
+    fn are_all_divisible_like_the_rules(ns: Vec<u64>, d: u8) {
+        let mut out = vec![false; ns.len()];
+        divisible_by_all(&ns, d.into(), &mut out);
+        for (n, result) in ns.iter().zip(out) {
+            assert_eq!(result, crate::divisible_by(*n, d.into()));
+        }
+    }
+
+    type Pair = (fn(&[u64], &mut [bool]), fn(u64) -> bool);
+
+    #[proptest]
+    fn are_all_divisible_like_each_rule(ns: Vec<u64>) {
+        let rules: [Pair; 13] = [
+            (divisible_by_0_all, crate::divisible_by_0),
+            (divisible_by_1_all, crate::divisible_by_1),
+            (divisible_by_2_all, crate::divisible_by_2),
+            (divisible_by_3_all, crate::divisible_by_3),
+            (divisible_by_4_all, crate::divisible_by_4),
+            (divisible_by_5_all, crate::divisible_by_5),
+            (divisible_by_6_all, crate::divisible_by_6),
+            (divisible_by_7_all, crate::divisible_by_7),
+            (divisible_by_8_all, crate::divisible_by_8),
+            (divisible_by_9_all, crate::divisible_by_9),
+            (divisible_by_10_all, crate::divisible_by_10),
+            (divisible_by_11_all, crate::divisible_by_11),
+            (divisible_by_12_all, crate::divisible_by_12),
+        ];
+
+        for (all, rule) in rules {
+            let mut out = vec![false; ns.len()];
+            all(&ns, &mut out);
+            let expected: Vec<bool> = ns.iter().map(|n| rule(*n)).collect();
+            assert_eq!(out, expected);
+        }
+    }
+
+    #[test]
+    #[should_panic(expected = "the output must have the same length as the input")]
+    fn is_not_divisible_by_all_into_a_shorter_output() {
+        divisible_by_all(&[1, 2, 3], 3, &mut [false; 2]);
+    }
+
+    #[proptest]
+    fn are_bits_like_all(ns: Vec<u64>, d: u8) {
+        let mut bits = vec![u64::MAX; ns.len().div_ceil(LANES)];
+        divisible_by_bits(&ns, d.into(), &mut bits);
+
+        let mut out = vec![false; ns.len()];
+        divisible_by_all(&ns, d.into(), &mut out);
+        for (i, result) in out.iter().enumerate() {
+            assert_eq!((bits[i / LANES] >> (i % LANES)) & 1 == 1, *result);
+        }
+
+        let set: u32 = bits.iter().map(|word| word.count_ones()).sum();
+        assert_eq!(set as usize, count_divisible_by(&ns, d.into()));
+    }
+
+    #[test]
+    #[should_panic(expected = "the output must have one word for every 64 numbers")]
+    fn is_not_divisible_by_bits_into_a_shorter_output() {
+        divisible_by_bits(&[0; 65], 3, &mut [0; 1]);
+    }
+
+    #[rstest]
+    #[case(&[], 3, &[])]
+    #[case(&[3, 4, 5, 6], 3, &[0, 3])]
+    #[case(&[3, 4, 5, 6], 0, &[])]
+    #[case(&[u64::MAX, 0, 1], 1, &[0, 1, 2])]
+    fn is_filtered_by(#[case] ns: &[u64], #[case] d: u64, #[case] expected: &[usize]) {
+        let indices: Vec<usize> = filter_divisible_by(ns, d).collect();
+        assert_eq!(indices, expected);
+        assert_eq!(count_divisible_by(ns, d), expected.len());
+    }
+
+    #[proptest]
+    fn are_filtered_like_all(ns: Vec<u64>, d: u8) {
+        let mut out = vec![false; ns.len()];
+        divisible_by_all(&ns, d.into(), &mut out);
+        let expected: Vec<usize> = (0..ns.len()).filter(|i| out[*i]).collect();
+        let indices: Vec<usize> = filter_divisible_by(&ns, d.into()).collect();
+        assert_eq!(indices, expected);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_kernel() {
+        let n: u16 = kani::any();
+        let d: u16 = kani::any();
+        assert_eq!(
+            Kernel::new(d.into()).divisible(n.into()),
+            n.checked_rem(d) == Some(0)
+        );
+    }
+}
@@ -0,0 +1,305 @@
+//! Divisibility checks using the binary representation of numbers.
+//!
+//! The digit rules carry over to base two (2) and base sixteen (16). Since
+//! two is one less than three (3), the bits of a number alternately add and
+//! subtract one to its remainder by three. Likewise four (4) is one less than
+//! five (5), sixteen is one more than fifteen (15) and one less than seventeen
+//! (17). Every rule counts the bits in a few masks with [`u64::count_ones`],
+//! weighs them by their position in a group and repeats until the number is
+//! small enough to recognize.
+
+/// The bits at even positions, which count as one (1) modulo three (3).
+const EVEN_BITS: u64 = 0x5555_5555_5555_5555;
+
+/// The bits at odd positions, which count as minus one (-1) modulo three (3).
+const ODD_BITS: u64 = 0xAAAA_AAAA_AAAA_AAAA;
+
+/// The lowest bit of every nibble.
+const NIBBLE_BITS: u64 = 0x1111_1111_1111_1111;
+
+/// The lowest bit of every nibble at an even position.
+const EVEN_NIBBLE_BITS: u64 = 0x0101_0101_0101_0101;
+
+/// The lowest bit of every nibble at an odd position.
+const ODD_NIBBLE_BITS: u64 = 0x1010_1010_1010_1010;
+
+/// Determines if the provided number is divisible by three (3), using the
+/// alternating sum of its bits.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::binary::{divisible_by_3};
+/// assert!(divisible_by_3(0b1111));
+/// assert!(!divisible_by_3(0b1011));
+/// ```
+pub const fn divisible_by_3(n: u64) -> bool {
+    if n > 3 {
+        let sum = popcount(n, EVEN_BITS) as i64 - popcount(n, ODD_BITS) as i64;
+        divisible_by_3(sum.unsigned_abs())
+    } else {
+        matches!(n, 0 | 3)
+    }
+}
+
+/// Determines if the provided number is divisible by five (5), using the
+/// alternating sum of its pairs of bits.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::binary::{divisible_by_5};
+/// assert!(divisible_by_5(0b1010));
+/// assert!(!divisible_by_5(0b1011));
+/// ```
+pub const fn divisible_by_5(n: u64) -> bool {
+    if n > 5 {
+        let sum = popcount(n, NIBBLE_BITS) as i64 + 2 * popcount(n, NIBBLE_BITS << 1) as i64
+            - popcount(n, NIBBLE_BITS << 2) as i64
+            - 2 * popcount(n, NIBBLE_BITS << 3) as i64;
+        divisible_by_5(sum.unsigned_abs())
+    } else {
+        matches!(n, 0 | 5)
+    }
+}
+
+/// Determines if the provided number is divisible by fifteen (15), using the
+/// sum of its nibbles, i.e. its hexadecimal digit sum.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::binary::{divisible_by_15};
+/// assert!(divisible_by_15(0xFF));
+/// assert!(!divisible_by_15(0xFE));
+/// ```
+pub const fn divisible_by_15(n: u64) -> bool {
+    if n > 15 {
+        let sum = popcount(n, NIBBLE_BITS) as u64
+            + 2 * popcount(n, NIBBLE_BITS << 1) as u64
+            + 4 * popcount(n, NIBBLE_BITS << 2) as u64
+            + 8 * popcount(n, NIBBLE_BITS << 3) as u64;
+        divisible_by_15(sum)
+    } else {
+        matches!(n, 0 | 15)
+    }
+}
+
+/// Determines if the provided number is divisible by seventeen (17), using
+/// the alternating sum of its nibbles, i.e. its hexadecimal digits.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::binary::{divisible_by_17};
+/// assert!(divisible_by_17(0x1122));
+/// assert!(!divisible_by_17(0x1234));
+/// ```
+pub const fn divisible_by_17(n: u64) -> bool {
+    if n > 17 {
+        let mut sum = 0;
+        let mut bit = 0;
+        while bit < 4 {
+            let even = popcount(n, EVEN_NIBBLE_BITS << bit) as i64;
+            let odd = popcount(n, ODD_NIBBLE_BITS << bit) as i64;
+            sum += (even - odd) * (1 << bit);
+            bit += 1;
+        }
+        divisible_by_17(sum.unsigned_abs())
+    } else {
+        matches!(n, 0 | 17)
+    }
+}
+
+const fn popcount(n: u64, mask: u64) -> u32 {
+    (n & mask).count_ones()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    #[case(0b1011)]
+    #[case(1 << 32)]
+    #[case(u64::MAX - 1)]
+    fn is_not_divisible_by_3(#[case] n: u64) {
+        let result = divisible_by_3(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(0b1111)]
+    #[case(0b101010)]
+    #[case(u64::MAX)]
+    fn is_divisible_by_3(#[case] n: u64) {
+        let result = divisible_by_3(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_3(base: u32) {
+        let n = 3 * (base as u64);
+        assert!(!divisible_by_3(n + 1));
+        assert!(!divisible_by_3(n + 2));
+    }
+
+    #[proptest]
+    fn are_divisible_by_3(base: u32) {
+        let n = 3 * (base as u64);
+        let result = divisible_by_3(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(0b1011)]
+    #[case(1 << 32)]
+    #[case(u64::MAX - 1)]
+    fn is_not_divisible_by_5(#[case] n: u64) {
+        let result = divisible_by_5(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(0b1010)]
+    #[case(0xFF)]
+    #[case(u64::MAX)]
+    fn is_divisible_by_5(#[case] n: u64) {
+        let result = divisible_by_5(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_5(base: u32) {
+        let n = 5 * (base as u64);
+        assert!(!divisible_by_5(n + 1));
+        assert!(!divisible_by_5(n + 2));
+        assert!(!divisible_by_5(n + 3));
+        assert!(!divisible_by_5(n + 4));
+    }
+
+    #[proptest]
+    fn are_divisible_by_5(base: u32) {
+        let n = 5 * (base as u64);
+        let result = divisible_by_5(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(0xFE)]
+    #[case(1 << 32)]
+    #[case(u64::MAX - 1)]
+    fn is_not_divisible_by_15(#[case] n: u64) {
+        let result = divisible_by_15(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(0xF)]
+    #[case(0xFF)]
+    #[case(u64::MAX)]
+    fn is_divisible_by_15(#[case] n: u64) {
+        let result = divisible_by_15(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_15(base: u32) {
+        let n = 15 * (base as u64);
+        for i in 1..15 {
+            assert!(!divisible_by_15(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_15(base: u32) {
+        let n = 15 * (base as u64);
+        let result = divisible_by_15(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(0x1234)]
+    #[case(1 << 32)]
+    #[case(u64::MAX - 1)]
+    fn is_not_divisible_by_17(#[case] n: u64) {
+        let result = divisible_by_17(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(0x11)]
+    #[case(0x1122)]
+    #[case(u64::MAX)]
+    fn is_divisible_by_17(#[case] n: u64) {
+        let result = divisible_by_17(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_17(base: u32) {
+        let n = 17 * (base as u64);
+        for i in 1..17 {
+            assert!(!divisible_by_17(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_17(base: u32) {
+        let n = 17 * (base as u64);
+        let result = divisible_by_17(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn agrees_with_the_decimal_rules(n: u64) {
+        assert_eq!(divisible_by_3(n), crate::divisible_by_3(n));
+        assert_eq!(divisible_by_5(n), crate::divisible_by_5(n));
+        assert_eq!(divisible_by_15(n), crate::divisible_by_15(n));
+        assert_eq!(divisible_by_17(n), crate::divisible_by_17(n));
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    pub fn check_divisible_by_3() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_3(n), n % 3 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    pub fn check_divisible_by_5() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_5(n), n % 5 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    pub fn check_divisible_by_15() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_15(n), n % 15 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(5)]
+    pub fn check_divisible_by_17() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_17(n), n % 17 == 0);
+    }
+}
@@ -0,0 +1,239 @@
+//! Divisibility rules for any divisor, composed from the rules of its factors.
+//!
+//! A number is divisible by a divisor exactly when it is divisible by each of
+//! the prime powers in its factorisation, the same way [`divisible_by_6`]
+//! combines the rules for two (2) and three (3). Because the prime powers are
+//! coprime, their remainders also determine the remainder by the divisor
+//! through the Chinese Remainder Theorem.
+//!
+//! [`divisible_by_6`]: crate::divisible_by_6
+
+use alloc::vec::Vec;
+
+use crate::utils::{last_digits, long_division_remainder, osculate_with_steps, osculator};
+use crate::{divisible_by, divisible_by_pow2, divisible_by_pow5, factorize, remainder_by};
+
+/// A divisor split into its coprime prime power factors.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{Composition};
+/// let composition = Composition::new(360).unwrap();
+/// assert_eq!(composition.factors(), &[(2, 3), (3, 2), (5, 1)]);
+/// assert!(composition.divisible(7200));
+/// assert_eq!(composition.remainder(7207), 7);
+/// ```
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Composition {
+    divisor: u64,
+    factors: Vec<(u64, u32)>,
+}
+
+impl Composition {
+    /// Splits the provided divisor into its prime power factors, or `None` if
+    /// the divisor is zero (0).
+    pub fn new(d: u64) -> Option<Self> {
+        if d == 0 {
+            return None;
+        }
+
+        Some(Self {
+            divisor: d,
+            factors: factorize(d),
+        })
+    }
+
+    /// Returns the divisor that was composed.
+    pub fn divisor(&self) -> u64 {
+        self.divisor
+    }
+
+    /// Returns the prime factors of the divisor with their exponents, in
+    /// ascending order of the prime.
+    pub fn factors(&self) -> &[(u64, u32)] {
+        &self.factors
+    }
+
+    /// Determines if the provided number is divisible by the divisor by
+    /// checking the rule for every prime power factor.
+    pub fn divisible(&self, n: u64) -> bool {
+        self.factors
+            .iter()
+            .all(|&(prime, exponent)| factor_divisible(n, prime, exponent))
+    }
+
+    /// Returns the remainder of the provided number by the divisor, combining
+    /// the remainders by every prime power factor.
+    pub fn remainder(&self, n: u64) -> u64 {
+        let (remainder, _) =
+            self.factors
+                .iter()
+                .fold((0, 1), |(remainder, modulus), &(prime, exponent)| {
+                    let factor = prime.pow(exponent);
+                    let factor_remainder = factor_remainder(n, prime, exponent);
+                    chinese_remainder(remainder, modulus, factor_remainder, factor)
+                });
+
+        remainder
+    }
+}
+
+fn factor_divisible(n: u64, prime: u64, exponent: u32) -> bool {
+    match prime {
+        2 => divisible_by_pow2(n, exponent),
+        5 => divisible_by_pow5(n, exponent),
+        _ if prime.pow(exponent) <= 100 => divisible_by(n, prime.pow(exponent)),
+        _ => factor_remainder(n, prime, exponent) == 0,
+    }
+}
+
+fn factor_remainder(n: u64, prime: u64, exponent: u32) -> u64 {
+    let factor = prime.pow(exponent);
+    match prime {
+        2 => last_digits(n, exponent as usize) & (factor - 1),
+        5 => long_division_remainder(last_digits(n, exponent as usize), factor),
+        _ if factor <= 12 => remainder_by(n, factor),
+        _ => {
+            let multiplier = osculator(factor);
+            if multiplier > u64::MAX / 10 {
+                return long_division_remainder(n, factor);
+            }
+
+            // Every step multiplies the number by the multiplier, the inverse
+            // of ten, so it is multiplied by ten once for every step to undo it.
+            let (reduced, steps) = osculate_with_steps(n, multiplier);
+            let reduced = long_division_remainder(reduced, factor);
+            multiply(reduced, power(10, steps, factor), factor)
+        }
+    }
+}
+
+fn chinese_remainder(r1: u64, m1: u64, r2: u64, m2: u64) -> (u64, u64) {
+    let difference =
+        (u128::from(r2) + u128::from(m2) - u128::from(r1) % u128::from(m2)) % u128::from(m2);
+    let k = multiply(difference as u64, inverse(m1 % m2, m2), m2);
+    let remainder = u128::from(r1) + u128::from(m1) * u128::from(k);
+    (remainder as u64, m1 * m2)
+}
+
+fn inverse(a: u64, m: u64) -> u64 {
+    let (mut old_r, mut r) = (i128::from(a), i128::from(m));
+    let (mut old_s, mut s) = (1_i128, 0_i128);
+    while r != 0 {
+        let quotient = old_r / r;
+        (old_r, r) = (r, old_r - quotient * r);
+        (old_s, s) = (s, old_s - quotient * s);
+    }
+
+    old_s.rem_euclid(i128::from(m)) as u64
+}
+
+fn multiply(a: u64, b: u64, m: u64) -> u64 {
+    ((u128::from(a) * u128::from(b)) % u128::from(m)) as u64
+}
+
+fn power(base: u64, exponent: u32, m: u64) -> u64 {
+    (0..exponent).fold(1 % m, |acc, _| multiply(acc, base, m))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, vec![])]
+    #[case(7, vec![(7, 1)])]
+    #[case(18, vec![(2, 1), (3, 2)])]
+    #[case(1001, vec![(7, 1), (11, 1), (13, 1)])]
+    #[case(1 << 63, vec![(2, 63)])]
+    #[case(u64::MAX, vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)])]
+    fn is_split_into_factors(#[case] d: u64, #[case] expected: Vec<(u64, u32)>) {
+        let composition = Composition::new(d).unwrap();
+        assert_eq!(composition.divisor(), d);
+        assert_eq!(composition.factors(), expected);
+    }
+
+    #[test]
+    fn is_not_composed_for_0() {
+        assert_eq!(Composition::new(0), None);
+    }
+
+    #[rstest]
+    #[case(1, 18)]
+    #[case(17, 24)]
+    #[case(1000, 36)]
+    #[case(1001, 45)]
+    #[case(121, 363)]
+    #[case(u64::MAX, 1 << 63)]
+    fn is_not_divisible_by_composed(#[case] n: u64, #[case] d: u64) {
+        let composition = Composition::new(d).unwrap();
+        assert!(!composition.divisible(n));
+    }
+
+    #[rstest]
+    #[case(0, 18)]
+    #[case(72, 24)]
+    #[case(7200, 60)]
+    #[case(1001, 1001)]
+    #[case(363, 121)]
+    #[case(u64::MAX, u64::MAX)]
+    fn is_divisible_by_composed(#[case] n: u64, #[case] d: u64) {
+        let composition = Composition::new(d).unwrap();
+        assert!(composition.divisible(n));
+    }
+
+    #[rstest]
+    #[case(0, 18, 0)]
+    #[case(7207, 360, 7)]
+    #[case(1000, 1001, 1000)]
+    #[case(u64::MAX, 10, 5)]
+    #[case(u64::MAX, 1 << 63, (1 << 63) - 1)]
+    #[case(u64::MAX, 3 * 3 * 3 * 3 * 3 * 3 * 3, 618)]
+    fn is_the_remainder_by_composed(#[case] n: u64, #[case] d: u64, #[case] expected: u64) {
+        let composition = Composition::new(d).unwrap();
+        assert_eq!(composition.remainder(n), expected);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_composed(base: u32, d: u8, offset: u8) {
+        let d = u64::from(d) + 2;
+        let n = d * u64::from(base) + u64::from(offset) % (d - 1) + 1;
+        let composition = Composition::new(d).unwrap();
+        assert!(!composition.divisible(n));
+    }
+
+    #[proptest]
+    fn are_divisible_by_composed(base: u32, d: u32) {
+        let d = u64::from(d) + 1;
+        let n = d * u64::from(base);
+        let composition = Composition::new(d).unwrap();
+        assert!(composition.divisible(n));
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_composed(n: u64, d: u32) {
+        let d = u64::from(d) + 1;
+        let composition = Composition::new(d).unwrap();
+        assert_eq!(composition.remainder(n), n % d);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_composition() {
+        let n: u8 = kani::any();
+        let d: u8 = kani::any();
+        kani::assume(d > 0 && d <= 36);
+        let composition = Composition::new(d.into()).unwrap();
+        assert_eq!(composition.divisible(n.into()), n % d == 0);
+        assert_eq!(composition.remainder(n.into()), (n % d).into());
+    }
+}
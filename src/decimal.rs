@@ -0,0 +1,497 @@
+//! Divisibility checks for arbitrarily large numbers written in decimal.
+//!
+//! The rules operate directly on the digits of the provided string, so the
+//! number is never parsed into a fixed-width integer and can be arbitrarily
+//! long.
+
+use alloc::vec::Vec;
+use core::fmt;
+
+/// The reason a string could not be interpreted as a decimal number.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ParseError {
+    /// The string is empty.
+    Empty,
+
+    /// The string starts or ends with whitespace.
+    Whitespace,
+
+    /// The string starts with a sign, i.e. `+` or `-`.
+    Sign,
+
+    /// The string contains a character that is not a decimal digit.
+    InvalidCharacter {
+        /// The offending character.
+        character: char,
+
+        /// The byte offset of the offending character in the string.
+        position: usize,
+    },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Empty => write!(f, "cannot parse a number from an empty string"),
+            ParseError::Whitespace => write!(f, "leading or trailing whitespace is not allowed"),
+            ParseError::Sign => write!(f, "signs are not allowed"),
+            ParseError::InvalidCharacter {
+                character,
+                position,
+            } => write!(f, "invalid character {character:?} at position {position}"),
+        }
+    }
+}
+
+impl core::error::Error for ParseError {}
+
+pub(crate) fn digits(n: &str) -> Result<&[u8], ParseError> {
+    let first = n.chars().next().ok_or(ParseError::Empty)?;
+    let last = n.chars().next_back().ok_or(ParseError::Empty)?;
+    if first.is_whitespace() || last.is_whitespace() {
+        return Err(ParseError::Whitespace);
+    }
+
+    if matches!(first, '+' | '-') {
+        return Err(ParseError::Sign);
+    }
+
+    match n.char_indices().find(|(_, char)| !char.is_ascii_digit()) {
+        Some((position, character)) => Err(ParseError::InvalidCharacter {
+            character,
+            position,
+        }),
+        None => Ok(n.as_bytes()),
+    }
+}
+
+fn alternating_digit_sum(digits: &[u8]) -> i64 {
+    digits
+        .iter()
+        .map(|digit| i64::from(digit - b'0'))
+        .enumerate()
+        .fold(0, |acc, (i, digit)| {
+            acc + ((if i % 2 == 0 { 1 } else { -1 }) * digit)
+        })
+}
+
+fn digit_sum(digits: &[u8]) -> u64 {
+    digits.iter().map(|digit| u64::from(digit - b'0')).sum()
+}
+
+fn last_digits(digits: &[u8], k: usize) -> u64 {
+    digits[digits.len().saturating_sub(k)..]
+        .iter()
+        .fold(0, |acc, digit| acc * 10 + u64::from(digit - b'0'))
+}
+
+fn add_small(digits: &mut Vec<u8>, mut carry: u64) {
+    for digit in digits.iter_mut().rev() {
+        if carry == 0 {
+            return;
+        }
+
+        let sum = u64::from(*digit - b'0') + carry;
+        *digit = b'0' + (sum % 10) as u8;
+        carry = sum / 10;
+    }
+
+    while carry > 0 {
+        digits.insert(0, b'0' + (carry % 10) as u8);
+        carry /= 10;
+    }
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// zero (0).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_0_str};
+/// let any_number = "42";
+/// assert_eq!(divisible_by_0_str(any_number), Ok(false));
+/// ```
+pub fn divisible_by_0_str(n: &str) -> Result<bool, ParseError> {
+    digits(n)?;
+    Ok(false)
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// one (1).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_1_str};
+/// let any_number = "42";
+/// assert_eq!(divisible_by_1_str(any_number), Ok(true));
+/// ```
+pub fn divisible_by_1_str(n: &str) -> Result<bool, ParseError> {
+    digits(n)?;
+    Ok(true)
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// two (2).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_2_str};
+/// assert_eq!(divisible_by_2_str("42"), Ok(true));
+/// assert_eq!(divisible_by_2_str("9"), Ok(false));
+/// ```
+pub fn divisible_by_2_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_2(last_digits(digits, 1)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// three (3).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_3_str};
+/// assert_eq!(divisible_by_3_str("9"), Ok(true));
+/// assert_eq!(divisible_by_3_str("8"), Ok(false));
+/// ```
+pub fn divisible_by_3_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_3(digit_sum(digits)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// four (4).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_4_str};
+/// assert_eq!(divisible_by_4_str("8"), Ok(true));
+/// assert_eq!(divisible_by_4_str("15"), Ok(false));
+/// ```
+pub fn divisible_by_4_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_4(last_digits(digits, 2)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// five (5).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_5_str};
+/// assert_eq!(divisible_by_5_str("15"), Ok(true));
+/// assert_eq!(divisible_by_5_str("12"), Ok(false));
+/// ```
+pub fn divisible_by_5_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_5(last_digits(digits, 1)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// six (6).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_6_str};
+/// assert_eq!(divisible_by_6_str("12"), Ok(true));
+/// assert_eq!(divisible_by_6_str("21"), Ok(false));
+/// ```
+pub fn divisible_by_6_str(n: &str) -> Result<bool, ParseError> {
+    Ok(divisible_by_2_str(n)? && divisible_by_3_str(n)?)
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// seven (7).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_7_str};
+/// assert_eq!(divisible_by_7_str("21"), Ok(true));
+/// assert_eq!(divisible_by_7_str("16"), Ok(false));
+/// ```
+pub fn divisible_by_7_str(n: &str) -> Result<bool, ParseError> {
+    let mut digits = digits(n)?.to_vec();
+    while digits.len() > 2 || last_digits(&digits, 2) > 50 {
+        let last = digits.pop().map_or(0, |digit| u64::from(digit - b'0'));
+        add_small(&mut digits, last * 5);
+    }
+
+    Ok(crate::divisible_by_7(last_digits(&digits, 2)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// eight (8).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_8_str};
+/// assert_eq!(divisible_by_8_str("16"), Ok(true));
+/// assert_eq!(divisible_by_8_str("18"), Ok(false));
+/// ```
+pub fn divisible_by_8_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_8(last_digits(digits, 3)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// nine (9).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_9_str};
+/// assert_eq!(divisible_by_9_str("18"), Ok(true));
+/// assert_eq!(divisible_by_9_str("20"), Ok(false));
+/// ```
+pub fn divisible_by_9_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_9(digit_sum(digits)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// ten (10).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_10_str};
+/// assert_eq!(divisible_by_10_str("20"), Ok(true));
+/// assert_eq!(divisible_by_10_str("22"), Ok(false));
+/// ```
+pub fn divisible_by_10_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_10(last_digits(digits, 1)))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// eleven (11).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_11_str};
+/// assert_eq!(divisible_by_11_str("22"), Ok(true));
+/// assert_eq!(divisible_by_11_str("36"), Ok(false));
+/// ```
+pub fn divisible_by_11_str(n: &str) -> Result<bool, ParseError> {
+    let digits = digits(n)?;
+    Ok(crate::divisible_by_11(
+        alternating_digit_sum(digits).unsigned_abs(),
+    ))
+}
+
+/// Determines if the number in the provided decimal string is divisible by
+/// twelve (12).
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if the string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_12_str};
+/// assert_eq!(divisible_by_12_str("36"), Ok(true));
+/// assert_eq!(divisible_by_12_str("42"), Ok(false));
+/// ```
+pub fn divisible_by_12_str(n: &str) -> Result<bool, ParseError> {
+    Ok(divisible_by_3_str(n)? && divisible_by_4_str(n)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    fn remainder(n: &str, d: u64) -> u64 {
+        n.bytes()
+            .fold(0, |acc, digit| (acc * 10 + u64::from(digit - b'0')) % d)
+    }
+
+    #[rstest]
+    #[case("", ParseError::Empty)]
+    #[case(" 42", ParseError::Whitespace)]
+    #[case("42\n", ParseError::Whitespace)]
+    #[case("+42", ParseError::Sign)]
+    #[case("-42", ParseError::Sign)]
+    #[case("-", ParseError::Sign)]
+    #[case("4-2", ParseError::InvalidCharacter { character: '-', position: 1 })]
+    #[case("4 2", ParseError::InvalidCharacter { character: ' ', position: 1 })]
+    #[case("42.0", ParseError::InvalidCharacter { character: '.', position: 2 })]
+    #[case("4é", ParseError::InvalidCharacter { character: 'é', position: 1 })]
+    #[case("٤٢", ParseError::InvalidCharacter { character: '٤', position: 0 })]
+    fn is_not_a_decimal_number(#[case] n: &str, #[case] expected: ParseError) {
+        assert_eq!(divisible_by_0_str(n), Err(expected));
+        assert_eq!(divisible_by_1_str(n), Err(expected));
+        assert_eq!(divisible_by_2_str(n), Err(expected));
+        assert_eq!(divisible_by_3_str(n), Err(expected));
+        assert_eq!(divisible_by_4_str(n), Err(expected));
+        assert_eq!(divisible_by_5_str(n), Err(expected));
+        assert_eq!(divisible_by_6_str(n), Err(expected));
+        assert_eq!(divisible_by_7_str(n), Err(expected));
+        assert_eq!(divisible_by_8_str(n), Err(expected));
+        assert_eq!(divisible_by_9_str(n), Err(expected));
+        assert_eq!(divisible_by_10_str(n), Err(expected));
+        assert_eq!(divisible_by_11_str(n), Err(expected));
+        assert_eq!(divisible_by_12_str(n), Err(expected));
+    }
+
+    #[rstest]
+    #[case("0")]
+    #[case("000")]
+    #[case("0000000000000000000000000000000000000000000000000000000000000000")]
+    fn zero_is_divisible_by_everything_non_zero(#[case] n: &str) {
+        assert_eq!(divisible_by_0_str(n), Ok(false));
+        assert_eq!(divisible_by_1_str(n), Ok(true));
+        assert_eq!(divisible_by_2_str(n), Ok(true));
+        assert_eq!(divisible_by_3_str(n), Ok(true));
+        assert_eq!(divisible_by_4_str(n), Ok(true));
+        assert_eq!(divisible_by_5_str(n), Ok(true));
+        assert_eq!(divisible_by_6_str(n), Ok(true));
+        assert_eq!(divisible_by_7_str(n), Ok(true));
+        assert_eq!(divisible_by_8_str(n), Ok(true));
+        assert_eq!(divisible_by_9_str(n), Ok(true));
+        assert_eq!(divisible_by_10_str(n), Ok(true));
+        assert_eq!(divisible_by_11_str(n), Ok(true));
+        assert_eq!(divisible_by_12_str(n), Ok(true));
+    }
+
+    #[rstest]
+    #[case("340282366920938463463374607431768211456")]
+    #[case("1000000000000000000000000000000000000000000000000000000000000000000001")]
+    #[case(
+        "8400000000000000000000000000000000000000000000000000000000000000000000\
+            0000000000000000000000000000000000000000000000000000000000000000000000\
+            0000000000000000000000000000000000000000000000000000000000000000000000"
+    )]
+    #[case(
+        "9999999999999999999999999999999999999999999999999999999999999999999999\
+            9999999999999999999999999999999999999999999999999999999999999999999999\
+            9999999999999999999999999999999999999999999999999999999999999999999999"
+    )]
+    fn are_checked_beyond_u128(#[case] n: &str) {
+        assert_eq!(divisible_by_2_str(n), Ok(remainder(n, 2) == 0));
+        assert_eq!(divisible_by_3_str(n), Ok(remainder(n, 3) == 0));
+        assert_eq!(divisible_by_4_str(n), Ok(remainder(n, 4) == 0));
+        assert_eq!(divisible_by_5_str(n), Ok(remainder(n, 5) == 0));
+        assert_eq!(divisible_by_6_str(n), Ok(remainder(n, 6) == 0));
+        assert_eq!(divisible_by_7_str(n), Ok(remainder(n, 7) == 0));
+        assert_eq!(divisible_by_8_str(n), Ok(remainder(n, 8) == 0));
+        assert_eq!(divisible_by_9_str(n), Ok(remainder(n, 9) == 0));
+        assert_eq!(divisible_by_10_str(n), Ok(remainder(n, 10) == 0));
+        assert_eq!(divisible_by_11_str(n), Ok(remainder(n, 11) == 0));
+        assert_eq!(divisible_by_12_str(n), Ok(remainder(n, 12) == 0));
+    }
+
+    #[proptest]
+    fn agree_with_the_integer_rules(n: u64) {
+        let s = n.to_string();
+        assert_eq!(divisible_by_2_str(&s), Ok(crate::divisible_by_2(n)));
+        assert_eq!(divisible_by_3_str(&s), Ok(crate::divisible_by_3(n)));
+        assert_eq!(divisible_by_4_str(&s), Ok(crate::divisible_by_4(n)));
+        assert_eq!(divisible_by_5_str(&s), Ok(crate::divisible_by_5(n)));
+        assert_eq!(divisible_by_6_str(&s), Ok(crate::divisible_by_6(n)));
+        assert_eq!(divisible_by_7_str(&s), Ok(crate::divisible_by_7(n)));
+        assert_eq!(divisible_by_8_str(&s), Ok(crate::divisible_by_8(n)));
+        assert_eq!(divisible_by_9_str(&s), Ok(crate::divisible_by_9(n)));
+        assert_eq!(divisible_by_10_str(&s), Ok(crate::divisible_by_10(n)));
+        assert_eq!(divisible_by_11_str(&s), Ok(crate::divisible_by_11(n)));
+        assert_eq!(divisible_by_12_str(&s), Ok(crate::divisible_by_12(n)));
+    }
+
+    #[proptest]
+    fn are_checked_with_many_digits(high: u128, low: u128) {
+        let n = format!("{high}{low:039}{high}");
+        assert_eq!(divisible_by_3_str(&n), Ok(remainder(&n, 3) == 0));
+        assert_eq!(divisible_by_7_str(&n), Ok(remainder(&n, 7) == 0));
+        assert_eq!(divisible_by_8_str(&n), Ok(remainder(&n, 8) == 0));
+        assert_eq!(divisible_by_11_str(&n), Ok(remainder(&n, 11) == 0));
+        assert_eq!(divisible_by_12_str(&n), Ok(remainder(&n, 12) == 0));
+    }
+
+    #[proptest]
+    fn are_not_numbers_with_surrounding_whitespace(n: u64) {
+        let s = format!("{n} ");
+        assert_eq!(divisible_by_7_str(&s), Err(ParseError::Whitespace));
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    pub fn check_digits() {
+        let bytes: [u8; 2] = kani::any();
+        if let Ok(n) = core::str::from_utf8(&bytes) {
+            assert_eq!(digits(n).is_ok(), bytes.iter().all(u8::is_ascii_digit));
+        }
+    }
+
+    #[kani::proof]
+    #[kani::unwind(4)]
+    pub fn check_last_digits() {
+        let bytes: [u8; 3] = kani::any();
+        kani::assume(bytes.iter().all(u8::is_ascii_digit));
+        assert!(last_digits(&bytes, 2) < 100);
+    }
+}
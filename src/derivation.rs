@@ -0,0 +1,291 @@
+//! Digit rules derived for any divisor that is coprime to ten (10).
+//!
+//! The rule for seven (7) works because 5 * 10 = 50 = 7 * 7 + 1, i.e. five (5)
+//! is the inverse of ten modulo seven. Replacing `10a + b` by `a + 5b` thus
+//! multiplies the number by five modulo seven, which keeps multiples of seven
+//! multiples of seven and other numbers not. Every divisor coprime to ten has
+//! such a forward multiplier, called its osculator, and a backward multiplier
+//! that is subtracted instead, e.g. `a - 2b` for seven.
+
+use alloc::format;
+use alloc::string::String;
+use core::fmt;
+
+use crate::explain::{explain_backward, explain_forward};
+use crate::utils::{long_division_remainder, osculate, osculate_backward, osculator};
+use crate::{divisible_by_2, divisible_by_5, Rule, Trace};
+
+/// A digit rule derived for a divisor that is coprime to ten (10).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{DerivedRule, Rule};
+/// let rule = DerivedRule::new(47).unwrap();
+/// assert_eq!(rule.forward(), 33);
+/// assert_eq!(rule.backward(), 14);
+/// assert!(rule.apply(2021));
+/// assert!(!rule.apply(2022));
+/// ```
+#[derive(Clone, Eq, Hash, PartialEq)]
+pub struct DerivedRule {
+    divisor: u64,
+    forward: u64,
+    backward: u64,
+    name: String,
+    description: String,
+}
+
+impl DerivedRule {
+    /// Derives the rule for the provided divisor, or `None` if the divisor is
+    /// divisible by two (2) or five (5), including zero (0).
+    pub fn new(d: u64) -> Option<Self> {
+        if divisible_by_2(d) || divisible_by_5(d) {
+            return None;
+        }
+
+        let forward = osculator(d);
+        let backward = (d - forward % d) % d;
+        let name = format!("osculator_{d}");
+        let description = format!(
+            "Adding {forward} times the last digit to the rest of the number, repeated while \
+             the number is at least {}, gives a multiple of {d}. So does subtracting {backward} \
+             times the last digit from the rest of the number, ignoring the sign.",
+            u128::from(forward) * 10
+        );
+
+        Some(Self {
+            divisor: d,
+            forward,
+            backward,
+            name,
+            description,
+        })
+    }
+
+    /// Returns the forward multiplier, the number the last digit is multiplied
+    /// by before it is added to the rest of the number.
+    pub fn forward(&self) -> u64 {
+        self.forward
+    }
+
+    /// Returns the backward multiplier, the number the last digit is
+    /// multiplied by before it is subtracted from the rest of the number.
+    pub fn backward(&self) -> u64 {
+        self.backward
+    }
+
+    /// Determines if the provided number is divisible by the divisor using
+    /// the forward multiplier, like [`divisible_by_7`] does.
+    ///
+    /// [`divisible_by_7`]: crate::divisible_by_7
+    pub fn divisible(&self, n: u64) -> bool {
+        let reduced = if self.forward <= u64::MAX / 10 {
+            osculate(n, self.forward)
+        } else {
+            n
+        };
+
+        long_division_remainder(reduced, self.divisor) == 0
+    }
+
+    /// Determines if the provided number is divisible by the divisor using
+    /// the backward multiplier.
+    pub fn divisible_backward(&self, n: u64) -> bool {
+        let reduced = if self.backward <= u64::MAX / 10 {
+            osculate_backward(n, self.backward)
+        } else {
+            n
+        };
+
+        long_division_remainder(reduced, self.divisor) == 0
+    }
+
+    /// Explains how the provided number is determined to be divisible by the
+    /// divisor using the forward multiplier, see [`explain`].
+    ///
+    /// [`explain`]: crate::explain
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use divisible_by::{DerivedRule};
+    /// let rule = DerivedRule::new(13).unwrap();
+    /// assert_eq!(
+    ///     rule.explain(2041).to_string(),
+    ///     "2041 is divisible by 13\n\
+    ///      - 2041 ends in 1, so 204 + 1 * 4 = 208\n\
+    ///      - 208 ends in 8, so 20 + 8 * 4 = 52\n\
+    ///      - 52 ends in 2, so 5 + 2 * 4 = 13\n\
+    ///      - 13 is a multiple of 13"
+    /// );
+    /// ```
+    pub fn explain(&self, n: u64) -> Trace {
+        explain_forward(n, self.divisor, self.forward)
+    }
+
+    /// Explains how the provided number is determined to be divisible by the
+    /// divisor using the backward multiplier, see [`explain`].
+    ///
+    /// [`explain`]: crate::explain
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use divisible_by::{DerivedRule};
+    /// let rule = DerivedRule::new(13).unwrap();
+    /// assert_eq!(
+    ///     rule.explain_backward(2041).to_string(),
+    ///     "2041 is divisible by 13\n\
+    ///      - 2041 ends in 1, so 204 - 1 * 9 = 195\n\
+    ///      - 195 ends in 5, so 5 * 9 - 19 = 26\n\
+    ///      - 26 is a multiple of 13"
+    /// );
+    /// ```
+    pub fn explain_backward(&self, n: u64) -> Trace {
+        explain_backward(n, self.divisor, self.backward)
+    }
+}
+
+impl Rule for DerivedRule {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn divisor(&self) -> u64 {
+        self.divisor
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn apply(&self, n: u64) -> bool {
+        self.divisible(n)
+    }
+}
+
+impl fmt::Debug for DerivedRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DerivedRule")
+            .field("divisor", &self.divisor)
+            .field("forward", &self.forward)
+            .field("backward", &self.backward)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 1, 0)]
+    #[case(3, 1, 2)]
+    #[case(7, 5, 2)]
+    #[case(9, 1, 8)]
+    #[case(11, 10, 1)]
+    #[case(13, 4, 9)]
+    #[case(47, 33, 14)]
+    #[case(u64::MAX - 2, 5534023222112865484, 12912720851596686129)]
+    fn has_the_osculators(#[case] d: u64, #[case] forward: u64, #[case] backward: u64) {
+        let rule = DerivedRule::new(d).unwrap();
+        assert_eq!(rule.divisor(), d);
+        assert_eq!(rule.forward(), forward);
+        assert_eq!(rule.backward(), backward);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(2)]
+    #[case(5)]
+    #[case(10)]
+    #[case(u64::MAX - 1)]
+    fn is_not_derived_for_divisors_sharing_a_factor_with_10(#[case] d: u64) {
+        assert_eq!(DerivedRule::new(d), None);
+    }
+
+    #[rstest]
+    #[case(1, 7)]
+    #[case(2022, 47)]
+    #[case(2040, 13)]
+    #[case(u64::MAX, 7)]
+    #[case(u64::MAX, u64::MAX - 2)]
+    fn is_not_divisible_by_derived(#[case] n: u64, #[case] d: u64) {
+        let rule = DerivedRule::new(d).unwrap();
+        assert!(!rule.divisible(n));
+        assert!(!rule.divisible_backward(n));
+    }
+
+    #[rstest]
+    #[case(0, 7)]
+    #[case(2021, 47)]
+    #[case(2041, 13)]
+    #[case(u64::MAX, 3)]
+    #[case(u64::MAX - 2, u64::MAX - 2)]
+    fn is_divisible_by_derived(#[case] n: u64, #[case] d: u64) {
+        let rule = DerivedRule::new(d).unwrap();
+        assert!(rule.divisible(n));
+        assert!(rule.divisible_backward(n));
+    }
+
+    #[test]
+    fn is_a_rule() {
+        let rule = DerivedRule::new(7).unwrap();
+        assert_eq!(rule.name(), "osculator_7");
+        assert_eq!(
+            rule.description(),
+            "Adding 5 times the last digit to the rest of the number, repeated while the \
+             number is at least 50, gives a multiple of 7. So does subtracting 2 times the \
+             last digit from the rest of the number, ignoring the sign."
+        );
+    }
+
+    #[proptest]
+    fn are_divisible_by_derived_like_the_remainder(n: u64, d: u32) {
+        if let Some(rule) = DerivedRule::new(d.into()) {
+            let expected = n % u64::from(d) == 0;
+            assert_eq!(rule.apply(n), expected);
+            assert_eq!(rule.divisible_backward(n), expected);
+        }
+    }
+
+    #[proptest]
+    fn are_explained_like_they_are_applied(n: u64, d: u16) {
+        if let Some(rule) = DerivedRule::new(d.into()) {
+            let forward = rule.explain(n);
+            let backward = rule.explain_backward(n);
+            assert_eq!(forward.divisible(), rule.divisible(n));
+            assert_eq!(backward.divisible(), rule.divisible_backward(n));
+        }
+    }
+
+    #[proptest]
+    fn have_an_inverse_of_10_as_forward(d: u64) {
+        if let Some(rule) = DerivedRule::new(d) {
+            let product = u128::from(rule.forward()) * 10 % u128::from(d);
+            assert_eq!(product, 1 % u128::from(d));
+            assert_eq!((rule.forward() + rule.backward()) % d, 0);
+        }
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_derived_rule() {
+        let n: u8 = kani::any();
+        let d: u8 = kani::any();
+        kani::assume(d < 50);
+        if let Some(rule) = DerivedRule::new(d.into()) {
+            assert_eq!(rule.divisible(n.into()), n % d == 0);
+            assert_eq!(rule.divisible_backward(n.into()), n % d == 0);
+        }
+    }
+}
@@ -0,0 +1,226 @@
+//! The decimal digits of a number, computed with arithmetic.
+//!
+//! Every digit rule looks at the digits of a number in some way, e.g. the rule
+//! for three (3) sums them and the rule for eleven (11) alternates their signs.
+//! [`Digits`] yields them one at a time from either end without allocating, so
+//! the same primitives can be used to write new rules.
+
+use core::iter::FusedIterator;
+
+use crate::utils;
+
+/// An iterator over the decimal digits of a number, see [`digits`].
+///
+/// The digits are yielded from the least significant digit to the most
+/// significant digit, or the other way around with [`Iterator::rev`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Digits {
+    /// The number formed by the digits that have not been yielded yet.
+    rest: u128,
+
+    /// The number of digits that have not been yielded yet, including zeros
+    /// at the front of `rest` that are left after yielding the digits before
+    /// them.
+    len: u32,
+}
+
+impl Digits {
+    pub(crate) const fn of(n: u128) -> Self {
+        Self {
+            rest: n,
+            len: utils::digit_count_in(n, 10),
+        }
+    }
+
+    pub(crate) const fn next_least_significant(&mut self) -> Option<u64> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let digit = (self.rest % 10) as u64;
+        self.rest /= 10;
+        self.len -= 1;
+        Some(digit)
+    }
+
+    pub(crate) const fn next_most_significant(&mut self) -> Option<u64> {
+        if self.len == 0 {
+            return None;
+        }
+
+        let place = 10u128.pow(self.len - 1);
+        let digit = (self.rest / place) as u64;
+        self.rest %= place;
+        self.len -= 1;
+        Some(digit)
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        self.next_least_significant()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len as usize;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Digits {
+    fn next_back(&mut self) -> Option<u64> {
+        self.next_most_significant()
+    }
+}
+
+impl ExactSizeIterator for Digits {}
+
+impl FusedIterator for Digits {}
+
+/// Returns an iterator over the decimal digits of the provided number, from
+/// the least significant digit to the most significant digit. Zero (0) has a
+/// single digit.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{digits};
+/// assert!(digits(1234).eq([4, 3, 2, 1]));
+/// assert!(digits(1234).rev().eq([1, 2, 3, 4]));
+/// assert_eq!(digits(0).len(), 1);
+/// ```
+pub const fn digits(n: u64) -> Digits {
+    Digits::of(n as u128)
+}
+
+/// Returns the sum of the decimal digits of the provided number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{digit_sum};
+/// assert_eq!(digit_sum(1234), 10);
+/// assert_eq!(digit_sum(0), 0);
+/// ```
+pub const fn digit_sum(n: u64) -> u64 {
+    utils::digit_sum(n as u128)
+}
+
+/// Returns the alternating sum of the decimal digits of the provided number,
+/// adding the most significant digit and alternately subtracting and adding
+/// the digits after it.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{alternating_digit_sum};
+/// assert_eq!(alternating_digit_sum(1234), 1 - 2 + 3 - 4);
+/// assert_eq!(alternating_digit_sum(918082), 22);
+/// ```
+pub const fn alternating_digit_sum(n: u64) -> i64 {
+    utils::alternating_digit_sum(n as u128)
+}
+
+/// Returns the last decimal digit of the provided number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{last_digit};
+/// assert_eq!(last_digit(1234), 4);
+/// assert_eq!(last_digit(0), 0);
+/// ```
+pub const fn last_digit(n: u64) -> u64 {
+    utils::last_digit(n as u128)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, vec![0])]
+    #[case(7, vec![7])]
+    #[case(120, vec![0, 2, 1])]
+    #[case(1001, vec![1, 0, 0, 1])]
+    #[case(u64::MAX, vec![5, 1, 6, 1, 5, 5, 9, 0, 7, 3, 7, 0, 4, 4, 7, 6, 4, 4, 8, 1])]
+    fn are_the_digits(#[case] n: u64, #[case] expected: Vec<u64>) {
+        assert_eq!(digits(n).collect::<Vec<u64>>(), expected);
+        assert_eq!(digits(n).len(), expected.len());
+    }
+
+    #[proptest]
+    fn are_the_digits_of_the_decimal_string(n: u64) {
+        let expected: Vec<u64> = n.to_string().bytes().map(|b| u64::from(b - b'0')).collect();
+        assert_eq!(digits(n).rev().collect::<Vec<u64>>(), expected);
+    }
+
+    #[proptest]
+    fn are_the_digits_from_both_ends(n: u64, front: u8) {
+        let expected: Vec<u64> = digits(n).collect();
+        let front = usize::from(front) % (expected.len() + 1);
+
+        let mut digits = digits(n);
+        let mut actual: Vec<u64> = digits.by_ref().take(front).collect();
+        assert_eq!(digits.len(), expected.len() - front);
+
+        let mut back: Vec<u64> = digits.rev().collect();
+        back.reverse();
+        actual.extend(back);
+        assert_eq!(actual, expected);
+    }
+
+    #[rstest]
+    #[case(0, 0, 0, 0)]
+    #[case(7, 7, 7, 7)]
+    #[case(1234, 10, -2, 4)]
+    #[case(918082, 28, 22, 2)]
+    #[case(u64::MAX, 87, -37, 5)]
+    fn are_the_digit_statistics(
+        #[case] n: u64,
+        #[case] sum: u64,
+        #[case] alternating_sum: i64,
+        #[case] last: u64,
+    ) {
+        assert_eq!(digit_sum(n), sum);
+        assert_eq!(alternating_digit_sum(n), alternating_sum);
+        assert_eq!(last_digit(n), last);
+    }
+
+    #[proptest]
+    fn are_the_digit_statistics_of_the_digits(n: u64) {
+        assert_eq!(digit_sum(n), digits(n).sum::<u64>());
+        assert_eq!(last_digit(n), digits(n).next().unwrap());
+
+        let expected = digits(n)
+            .rev()
+            .zip([1, -1].into_iter().cycle())
+            .map(|(digit, sign)| sign * digit as i64)
+            .sum::<i64>();
+        assert_eq!(alternating_digit_sum(n), expected);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_digits() {
+        let n: u16 = kani::any();
+        let mut value = 0;
+        let mut place = 1;
+        for digit in digits(n.into()) {
+            assert!(digit < 10);
+            value += digit * place;
+            place *= 10;
+        }
+        assert!(value == u64::from(n));
+    }
+}
@@ -0,0 +1,413 @@
+//! Sets of the divisors from zero (0) through twelve (12) of a number.
+
+use core::fmt;
+use core::iter::FusedIterator;
+use core::ops::{BitAnd, BitOr, BitXor, Sub};
+
+use crate::digits;
+use crate::{
+    divisible_by_10, divisible_by_11, divisible_by_2, divisible_by_3, divisible_by_4,
+    divisible_by_5, divisible_by_7, divisible_by_8, divisible_by_9,
+};
+
+/// The largest number that can be in a [`DivisorSet`].
+const MAX: u64 = 12;
+
+/// The bits of the numbers that can be in a [`DivisorSet`].
+const ALL: u16 = (1 << (MAX + 1)) - 1;
+
+/// A set of numbers from zero (0) through twelve (12), stored as a bitmask.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisors_up_to_12, DivisorSet};
+/// let divisors = divisors_up_to_12(42);
+/// assert!(divisors.contains(7));
+/// assert_eq!(divisors.to_string(), "{1, 2, 3, 6, 7}");
+///
+/// let even: DivisorSet = [2, 4, 6, 8, 10, 12].into_iter().collect();
+/// assert_eq!((divisors & even).to_string(), "{2, 6}");
+/// ```
+#[derive(Clone, Copy, Default, Eq, Hash, PartialEq)]
+pub struct DivisorSet {
+    bits: u16,
+}
+
+impl DivisorSet {
+    /// Creates an empty set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a set from a bitmask where bit `d` is set if `d` is in the set.
+    /// Bits above twelve (12) are ignored.
+    pub fn from_bits(bits: u16) -> Self {
+        Self { bits: bits & ALL }
+    }
+
+    /// Returns the bitmask where bit `d` is set if `d` is in the set.
+    pub fn bits(self) -> u16 {
+        self.bits
+    }
+
+    /// Returns `true` if the provided number is in the set.
+    pub fn contains(self, d: u64) -> bool {
+        d <= MAX && self.bits & (1 << d) != 0
+    }
+
+    /// Adds the provided number to the set, returning `true` if it was not in
+    /// the set yet.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the number is greater than twelve (12).
+    pub fn insert(&mut self, d: u64) -> bool {
+        assert!(d <= MAX, "a divisor set holds 0 through 12, got {d}");
+        let inserted = !self.contains(d);
+        self.bits |= 1 << d;
+        inserted
+    }
+
+    /// Removes the provided number from the set, returning `true` if it was in
+    /// the set.
+    pub fn remove(&mut self, d: u64) -> bool {
+        let removed = self.contains(d);
+        if removed {
+            self.bits &= !(1 << d);
+        }
+
+        removed
+    }
+
+    /// Returns the number of numbers in the set.
+    pub fn len(self) -> usize {
+        self.bits.count_ones() as usize
+    }
+
+    /// Returns `true` if the set is empty.
+    pub fn is_empty(self) -> bool {
+        self.bits == 0
+    }
+
+    /// Returns an iterator over the numbers in the set, in ascending order.
+    pub fn iter(self) -> DivisorSetIter {
+        DivisorSetIter { bits: self.bits }
+    }
+
+    /// Returns the numbers that are in either set.
+    pub fn union(self, other: Self) -> Self {
+        Self::from_bits(self.bits | other.bits)
+    }
+
+    /// Returns the numbers that are in both sets.
+    pub fn intersection(self, other: Self) -> Self {
+        Self::from_bits(self.bits & other.bits)
+    }
+
+    /// Returns the numbers that are in this set but not in the other.
+    pub fn difference(self, other: Self) -> Self {
+        Self::from_bits(self.bits & !other.bits)
+    }
+
+    /// Returns the numbers that are in exactly one of the sets.
+    pub fn symmetric_difference(self, other: Self) -> Self {
+        Self::from_bits(self.bits ^ other.bits)
+    }
+
+    /// Returns `true` if every number in this set is also in the other.
+    pub fn is_subset(self, other: Self) -> bool {
+        self.difference(other).is_empty()
+    }
+}
+
+impl BitOr for DivisorSet {
+    type Output = Self;
+
+    fn bitor(self, other: Self) -> Self {
+        self.union(other)
+    }
+}
+
+impl BitAnd for DivisorSet {
+    type Output = Self;
+
+    fn bitand(self, other: Self) -> Self {
+        self.intersection(other)
+    }
+}
+
+impl BitXor for DivisorSet {
+    type Output = Self;
+
+    fn bitxor(self, other: Self) -> Self {
+        self.symmetric_difference(other)
+    }
+}
+
+impl Sub for DivisorSet {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        self.difference(other)
+    }
+}
+
+impl fmt::Debug for DivisorSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl fmt::Display for DivisorSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{{")?;
+        for (i, d) in self.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+
+            write!(f, "{d}")?;
+        }
+
+        write!(f, "}}")
+    }
+}
+
+impl FromIterator<u64> for DivisorSet {
+    fn from_iter<I: IntoIterator<Item = u64>>(iter: I) -> Self {
+        let mut set = Self::new();
+        for d in iter {
+            set.insert(d);
+        }
+
+        set
+    }
+}
+
+impl IntoIterator for DivisorSet {
+    type Item = u64;
+    type IntoIter = DivisorSetIter;
+
+    fn into_iter(self) -> DivisorSetIter {
+        self.iter()
+    }
+}
+
+/// An iterator over the numbers in a [`DivisorSet`], in ascending order.
+#[derive(Clone, Debug)]
+pub struct DivisorSetIter {
+    bits: u16,
+}
+
+impl Iterator for DivisorSetIter {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if self.bits == 0 {
+            return None;
+        }
+
+        let d = self.bits.trailing_zeros();
+        self.bits &= self.bits - 1;
+        Some(d.into())
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.bits.count_ones() as usize;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for DivisorSetIter {}
+
+impl FusedIterator for DivisorSetIter {}
+
+/// Returns the set of numbers from zero (0) through twelve (12) that divide the
+/// provided number.
+///
+/// The digits of the number are visited once to compute its last three (3)
+/// digits, digit sum and alternating sum of three digit blocks, which decide
+/// every rule, e.g. the digit sum decides both [`divisible_by_3`] and
+/// [`divisible_by_9`].
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisors_up_to_12};
+/// let divisors = divisors_up_to_12(360);
+/// assert_eq!(divisors.to_string(), "{1, 2, 3, 4, 5, 6, 8, 9, 10, 12}");
+/// assert!(!divisors.contains(7));
+/// ```
+pub fn divisors_up_to_12(n: u64) -> DivisorSet {
+    let (last_digits, digit_sum, block_sum) = digit_statistics(n);
+    let block_sum = block_sum.unsigned_abs();
+
+    let two = divisible_by_2(last_digits);
+    let three = divisible_by_3(digit_sum);
+    let four = divisible_by_4(last_digits);
+    let rules = [
+        (1, true),
+        (2, two),
+        (3, three),
+        (4, four),
+        (5, divisible_by_5(last_digits)),
+        (6, two && three),
+        (7, divisible_by_7(block_sum)),
+        (8, divisible_by_8(last_digits)),
+        (9, divisible_by_9(digit_sum)),
+        (10, divisible_by_10(last_digits)),
+        (11, divisible_by_11(block_sum)),
+        (12, three && four),
+    ];
+
+    let bits = rules
+        .into_iter()
+        .filter(|(_, divisible)| *divisible)
+        .fold(0, |bits, (d, _)| bits | (1 << d));
+    DivisorSet::from_bits(bits)
+}
+
+/// Returns the number formed by the last three (3) digits, the digit sum and
+/// the alternating sum of the blocks of three digits of the provided number.
+fn digit_statistics(n: u64) -> (u64, u64, i64) {
+    const POWERS: [u64; 3] = [1, 10, 100];
+    digits(n).enumerate().fold(
+        (0, 0, 0),
+        |(last_digits, digit_sum, block_sum), (i, digit)| {
+            let value = digit * POWERS[i % 3];
+            let sign = if (i / 3) % 2 == 0 { 1 } else { -1 };
+            (
+                if i < 3 {
+                    last_digits + value
+                } else {
+                    last_digits
+                },
+                digit_sum + digit,
+                block_sum + sign * value as i64,
+            )
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, "{1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12}")]
+    #[case(1, "{1}")]
+    #[case(42, "{1, 2, 3, 6, 7}")]
+    #[case(1001, "{1, 7, 11}")]
+    #[case(27720, "{1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12}")]
+    #[case(u64::MAX, "{1, 3, 5}")]
+    fn is_the_set_of_divisors(#[case] n: u64, #[case] expected: &str) {
+        assert_eq!(divisors_up_to_12(n).to_string(), expected);
+    }
+
+    #[proptest]
+    fn agrees_with_the_rules(n: u64) {
+        let divisors = divisors_up_to_12(n);
+        for d in 0..=MAX {
+            assert_eq!(divisors.contains(d), crate::divisible_by(n, d));
+        }
+    }
+
+    #[rstest]
+    #[case(0, (0, 0, 0))]
+    #[case(6468, (468, 24, 462))]
+    #[case(1001, (1, 2, 0))]
+    #[case(u64::MAX, (615, 87, 1016))]
+    fn digit_statistics_are_computed_at_once(#[case] n: u64, #[case] expected: (u64, u64, i64)) {
+        assert_eq!(digit_statistics(n), expected);
+    }
+
+    #[test]
+    fn is_empty_when_new() {
+        let set = DivisorSet::new();
+        assert!(set.is_empty());
+        assert_eq!(set.len(), 0);
+        assert_eq!(set.to_string(), "{}");
+        assert_eq!(format!("{set:?}"), "{}");
+    }
+
+    #[test]
+    fn is_changed_by_inserting_and_removing() {
+        let mut set = DivisorSet::new();
+        assert!(set.insert(0));
+        assert!(set.insert(12));
+        assert!(!set.insert(12));
+        assert_eq!(set.bits(), 0b1_0000_0000_0001);
+        assert!(set.remove(0));
+        assert!(!set.remove(0));
+        assert!(!set.remove(13));
+        assert_eq!(set.iter().collect::<Vec<u64>>(), [12]);
+    }
+
+    #[test]
+    #[should_panic(expected = "a divisor set holds 0 through 12, got 13")]
+    fn is_not_inserted_beyond_12() {
+        DivisorSet::new().insert(13);
+    }
+
+    #[rstest]
+    #[case(0, false)]
+    #[case(12, true)]
+    #[case(13, false)]
+    #[case(u64::MAX, false)]
+    fn contains_only_numbers_up_to_12(#[case] d: u64, #[case] expected: bool) {
+        let set = DivisorSet::from_bits(u16::MAX - 1);
+        assert_eq!(set.contains(d), expected);
+    }
+
+    #[test]
+    fn is_combined_with_set_operations() {
+        let six: DivisorSet = [1, 2, 3, 6].into_iter().collect();
+        let ten: DivisorSet = [1, 2, 5, 10].into_iter().collect();
+        assert_eq!((six | ten).to_string(), "{1, 2, 3, 5, 6, 10}");
+        assert_eq!((six & ten).to_string(), "{1, 2}");
+        assert_eq!((six - ten).to_string(), "{3, 6}");
+        assert_eq!((six ^ ten).to_string(), "{3, 5, 6, 10}");
+        assert!((six & ten).is_subset(six));
+        assert!(!six.is_subset(ten));
+    }
+
+    #[proptest]
+    fn are_set_operations_like_the_bits(a: u16, b: u16) {
+        let (a, b) = (DivisorSet::from_bits(a), DivisorSet::from_bits(b));
+        for d in 0..=MAX {
+            assert_eq!((a | b).contains(d), a.contains(d) || b.contains(d));
+            assert_eq!((a & b).contains(d), a.contains(d) && b.contains(d));
+            assert_eq!((a - b).contains(d), a.contains(d) && !b.contains(d));
+            assert_eq!((a ^ b).contains(d), a.contains(d) != b.contains(d));
+        }
+    }
+
+    #[proptest]
+    fn is_iterated_in_ascending_order(bits: u16) {
+        let set = DivisorSet::from_bits(bits);
+        let numbers: Vec<u64> = set.into_iter().collect();
+        let expected: Vec<u64> = (0..=MAX).filter(|d| set.contains(*d)).collect();
+        assert_eq!(numbers, expected);
+        assert_eq!(set.iter().len(), set.len());
+        assert_eq!(numbers.into_iter().collect::<DivisorSet>(), set);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_divisors_up_to_12() {
+        let n: u16 = kani::any();
+        let d: u64 = kani::any();
+        kani::assume(d > 0 && d <= MAX);
+        let divisors = divisors_up_to_12(n.into());
+        assert_eq!(divisors.contains(d), u64::from(n) % d == 0);
+    }
+}
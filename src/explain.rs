@@ -0,0 +1,549 @@
+//! Step-by-step explanations of how a rule decides divisibility.
+
+use alloc::string::ToString;
+use alloc::vec::Vec;
+use core::fmt;
+
+use crate::utils::{alternating_digit_sum, digit_sum, last_digits, osculator, split_last_digit};
+use crate::{divisible_by, Composition};
+
+/// A single step taken by a rule while deciding divisibility.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Step {
+    /// The last `count` digits of `n` were taken.
+    LastDigits {
+        /// The number whose digits were taken.
+        n: u64,
+
+        /// The number of digits that were taken.
+        count: u32,
+
+        /// The number formed by the digits that were taken.
+        last: u64,
+    },
+
+    /// The digits of `n` were summed.
+    DigitSum {
+        /// The number whose digits were summed.
+        n: u64,
+
+        /// The sum of the digits.
+        sum: u64,
+    },
+
+    /// The digits of `n` were summed with alternating signs.
+    AlternatingDigitSum {
+        /// The number whose digits were summed.
+        n: u64,
+
+        /// The alternating sum of the digits.
+        sum: i64,
+    },
+
+    /// `n` was halved by shifting it one bit to the right.
+    Shift {
+        /// The number that was halved.
+        n: u64,
+
+        /// The halved number, rounded down.
+        shifted: u64,
+    },
+
+    /// The last digit of `n` was multiplied and added to the rest of `n`, as
+    /// the rule for seven (7) does with a multiplier of five (5).
+    Osculate {
+        /// The number that was reduced.
+        n: u64,
+
+        /// All but the last digit of the number.
+        rest: u64,
+
+        /// The last digit of the number.
+        last: u64,
+
+        /// The multiplier of the last digit.
+        multiplier: u64,
+
+        /// The reduced number, i.e. `rest + last * multiplier`.
+        result: u64,
+    },
+
+    /// The last digit of `n` was multiplied and subtracted from the rest of
+    /// `n`, as the backward rule for seven (7) does with a multiplier of two
+    /// (2). The sign of the difference is dropped.
+    OsculateBackward {
+        /// The number that was reduced.
+        n: u64,
+
+        /// All but the last digit of the number.
+        rest: u64,
+
+        /// The last digit of the number.
+        last: u64,
+
+        /// The multiplier of the last digit.
+        multiplier: u64,
+
+        /// The reduced number, i.e. `|rest - last * multiplier|`.
+        result: u64,
+    },
+
+    /// `n` is small enough to be recognized as a multiple of `d` or not.
+    Lookup {
+        /// The number that was recognized.
+        n: u64,
+
+        /// The divisor.
+        d: u64,
+
+        /// Whether the number is a multiple of the divisor.
+        divisible: bool,
+    },
+
+    /// The divisibility by a factor of the divisor was decided.
+    Factor(Trace),
+}
+
+impl fmt::Display for Step {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Step::LastDigits { n, count: 1, last } => {
+                write!(f, "the last digit of {n} is {last}")
+            }
+            Step::LastDigits { n, count, last } => {
+                write!(f, "the last {count} digits of {n} are {last}")
+            }
+            Step::DigitSum { n, sum } => write!(f, "the digit sum of {n} is {sum}"),
+            Step::AlternatingDigitSum { n, sum } => {
+                write!(f, "the alternating digit sum of {n} is {sum}")
+            }
+            Step::Shift { n, shifted } => write!(f, "half of {n} is {shifted}"),
+            Step::Osculate {
+                n,
+                rest,
+                last,
+                multiplier,
+                result,
+            } => write!(
+                f,
+                "{n} ends in {last}, so {rest} + {last} * {multiplier} = {result}"
+            ),
+            Step::OsculateBackward {
+                n,
+                rest,
+                last,
+                multiplier,
+                result,
+            } if rest < &(last * multiplier) => write!(
+                f,
+                "{n} ends in {last}, so {last} * {multiplier} - {rest} = {result}"
+            ),
+            Step::OsculateBackward {
+                n,
+                rest,
+                last,
+                multiplier,
+                result,
+            } => write!(
+                f,
+                "{n} ends in {last}, so {rest} - {last} * {multiplier} = {result}"
+            ),
+            Step::Lookup { n, d, divisible } => {
+                let is = if *divisible { "is" } else { "is not" };
+                write!(f, "{n} {is} a multiple of {d}")
+            }
+            Step::Factor(trace) => {
+                write!(f, "{}", trace.to_string().replace('\n', "\n  "))
+            }
+        }
+    }
+}
+
+/// The steps taken by a rule to decide if a number is divisible by a divisor,
+/// together with the verdict.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{explain};
+/// let trace = explain(434, 7);
+/// assert!(trace.divisible());
+/// assert_eq!(
+///     trace.to_string(),
+///     "434 is divisible by 7\n\
+///      - 434 ends in 4, so 43 + 4 * 5 = 63\n\
+///      - 63 ends in 3, so 6 + 3 * 5 = 21\n\
+///      - 21 is a multiple of 7"
+/// );
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Trace {
+    n: u64,
+    d: u64,
+    steps: Vec<Step>,
+    divisible: bool,
+}
+
+impl Trace {
+    /// Returns the number that was checked.
+    pub fn n(&self) -> u64 {
+        self.n
+    }
+
+    /// Returns the divisor that was checked.
+    pub fn divisor(&self) -> u64 {
+        self.d
+    }
+
+    /// Returns the steps taken, in order.
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Returns whether the number is divisible by the divisor.
+    pub fn divisible(&self) -> bool {
+        self.divisible
+    }
+}
+
+impl fmt::Display for Trace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let is = if self.divisible { "is" } else { "is not" };
+        write!(f, "{} {is} divisible by {}", self.n, self.d)?;
+        for step in &self.steps {
+            write!(f, "\n- {step}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Explains how the provided number is determined to be divisible by the
+/// provided divisor, or not.
+///
+/// The explanation follows the same rule as [`divisible_by`], e.g. the digit
+/// sums of [`divisible_by_3`] and the shifts of [`divisible_by_8`]. Divisors
+/// without a dedicated rule are explained through their prime power factors,
+/// see [`Composition`].
+///
+/// [`divisible_by_3`]: crate::divisible_by_3
+/// [`divisible_by_8`]: crate::divisible_by_8
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{explain, Step};
+/// let trace = explain(12, 3);
+/// assert_eq!(
+///     trace.steps(),
+///     &[
+///         Step::DigitSum { n: 12, sum: 3 },
+///         Step::Lookup { n: 3, d: 3, divisible: true },
+///     ]
+/// );
+/// ```
+pub fn explain(n: u64, d: u64) -> Trace {
+    let mut steps = Vec::new();
+    let divisible = match d {
+        0 => false,
+        1 => true,
+        2 | 5 | 10 => explain_last_digits(n, d, 1, &mut steps),
+        3 | 9 => explain_digit_sum(n, d, &mut steps),
+        4 => explain_shifts(n, 2, &mut steps),
+        6 => explain_factors(n, &[2, 3], &mut steps),
+        7 => explain_osculation(n, d, 5, &mut steps),
+        8 => explain_shifts(n, 3, &mut steps),
+        11 => explain_alternating_digit_sum(n, &mut steps),
+        12 => explain_factors(n, &[3, 4], &mut steps),
+        _ => explain_composition(n, d, &mut steps),
+    };
+
+    Trace {
+        n,
+        d,
+        steps,
+        divisible,
+    }
+}
+
+/// Explains the osculation of the provided number with the provided forward
+/// multiplier, the inverse of ten (10) modulo the divisor.
+pub(crate) fn explain_forward(n: u64, d: u64, multiplier: u64) -> Trace {
+    let mut steps = Vec::new();
+    let divisible = if multiplier <= u64::MAX / 10 {
+        explain_osculation(n, d, multiplier, &mut steps)
+    } else {
+        lookup(n, d, &mut steps)
+    };
+
+    Trace {
+        n,
+        d,
+        steps,
+        divisible,
+    }
+}
+
+/// Explains the osculation of the provided number with the provided backward
+/// multiplier, the divisor minus the forward multiplier.
+pub(crate) fn explain_backward(n: u64, d: u64, multiplier: u64) -> Trace {
+    let mut steps = Vec::new();
+    let divisible = if multiplier <= u64::MAX / 10 {
+        explain_backward_osculation(n, d, multiplier, &mut steps)
+    } else {
+        lookup(n, d, &mut steps)
+    };
+
+    Trace {
+        n,
+        d,
+        steps,
+        divisible,
+    }
+}
+
+fn explain_last_digits(n: u64, d: u64, count: u32, steps: &mut Vec<Step>) -> bool {
+    let last = last_digits(n, count as usize);
+    steps.push(Step::LastDigits { n, count, last });
+    lookup(last, d, steps)
+}
+
+fn explain_digit_sum(n: u64, d: u64, steps: &mut Vec<Step>) -> bool {
+    let mut n = n;
+    while n >= 10 {
+        let sum = digit_sum(n.into());
+        steps.push(Step::DigitSum { n, sum });
+        n = sum;
+    }
+
+    lookup(n, d, steps)
+}
+
+fn explain_alternating_digit_sum(n: u64, steps: &mut Vec<Step>) -> bool {
+    let mut n = n;
+    while n >= 11 {
+        let sum = alternating_digit_sum(n.into());
+        steps.push(Step::AlternatingDigitSum { n, sum });
+        n = sum.unsigned_abs();
+    }
+
+    lookup(n, 11, steps)
+}
+
+fn explain_shifts(n: u64, count: u32, steps: &mut Vec<Step>) -> bool {
+    let mut n = n;
+    for i in 0..count {
+        if !explain_last_digits(n, 2, 1, steps) {
+            return false;
+        }
+
+        if i + 1 < count {
+            let shifted = n >> 1;
+            steps.push(Step::Shift { n, shifted });
+            n = shifted;
+        }
+    }
+
+    true
+}
+
+fn explain_osculation(n: u64, d: u64, multiplier: u64, steps: &mut Vec<Step>) -> bool {
+    let mut n = n;
+    while n >= 10 * multiplier {
+        let (rest, last) = split_last_digit(n);
+        let result = rest + last * multiplier;
+        steps.push(Step::Osculate {
+            n,
+            rest,
+            last,
+            multiplier,
+            result,
+        });
+        n = result;
+    }
+
+    lookup(n, d, steps)
+}
+
+fn explain_backward_osculation(n: u64, d: u64, multiplier: u64, steps: &mut Vec<Step>) -> bool {
+    let mut n = n;
+    while n >= 10 * multiplier.max(1) {
+        let (rest, last) = split_last_digit(n);
+        let result = rest.abs_diff(last * multiplier);
+        steps.push(Step::OsculateBackward {
+            n,
+            rest,
+            last,
+            multiplier,
+            result,
+        });
+        n = result;
+    }
+
+    lookup(n, d, steps)
+}
+
+fn explain_factors(n: u64, factors: &[u64], steps: &mut Vec<Step>) -> bool {
+    for &factor in factors {
+        let trace = explain(n, factor);
+        let divisible = trace.divisible;
+        steps.push(Step::Factor(trace));
+        if !divisible {
+            return false;
+        }
+    }
+
+    true
+}
+
+fn explain_composition(n: u64, d: u64, steps: &mut Vec<Step>) -> bool {
+    let composition = Composition::new(d).expect("d is at least 2 when composing");
+    match composition.factors() {
+        &[(2 | 5, exponent)] => explain_last_digits(n, d, exponent, steps),
+        &[_] => match osculator(d) {
+            multiplier if multiplier <= u64::MAX / 10 => {
+                explain_osculation(n, d, multiplier, steps)
+            }
+            _ => lookup(n, d, steps),
+        },
+        factors => {
+            let factors: Vec<u64> = factors
+                .iter()
+                .map(|(prime, exponent)| prime.pow(*exponent))
+                .collect();
+            explain_factors(n, &factors, steps)
+        }
+    }
+}
+
+fn lookup(n: u64, d: u64, steps: &mut Vec<Step>) -> bool {
+    let divisible = divisible_by(n, d);
+    steps.push(Step::Lookup { n, d, divisible });
+    divisible
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(42, 0, "42 is not divisible by 0")]
+    #[case(42, 1, "42 is divisible by 1")]
+    #[case(
+        42,
+        2,
+        "42 is divisible by 2\n\
+         - the last digit of 42 is 2\n\
+         - 2 is a multiple of 2"
+    )]
+    #[case(
+        6468,
+        3,
+        "6468 is divisible by 3\n\
+         - the digit sum of 6468 is 24\n\
+         - the digit sum of 24 is 6\n\
+         - 6 is a multiple of 3"
+    )]
+    #[case(
+        22,
+        8,
+        "22 is not divisible by 8\n\
+         - the last digit of 22 is 2\n\
+         - 2 is a multiple of 2\n\
+         - half of 22 is 11\n\
+         - the last digit of 11 is 1\n\
+         - 1 is not a multiple of 2"
+    )]
+    #[case(
+        21,
+        6,
+        "21 is not divisible by 6\n\
+         - 21 is not divisible by 2\n  \
+           - the last digit of 21 is 1\n  \
+           - 1 is not a multiple of 2"
+    )]
+    #[case(
+        918082,
+        11,
+        "918082 is divisible by 11\n\
+         - the alternating digit sum of 918082 is 22\n\
+         - the alternating digit sum of 22 is 0\n\
+         - 0 is a multiple of 11"
+    )]
+    #[case(
+        1625,
+        125,
+        "1625 is divisible by 125\n\
+         - the last 3 digits of 1625 are 625\n\
+         - 625 is a multiple of 125"
+    )]
+    #[case(
+        390,
+        13,
+        "390 is divisible by 13\n\
+         - 390 ends in 0, so 39 + 0 * 4 = 39\n\
+         - 39 is a multiple of 13"
+    )]
+    fn is_displayed_step_by_step(#[case] n: u64, #[case] d: u64, #[case] expected: &str) {
+        assert_eq!(explain(n, d).to_string(), expected);
+    }
+
+    #[test]
+    fn is_explained_through_factors() {
+        let trace = explain(7200, 60);
+        let factors: Vec<u64> = trace
+            .steps()
+            .iter()
+            .map(|step| match step {
+                Step::Factor(trace) => trace.divisor(),
+                _ => panic!("expected only factors, got {step:?}"),
+            })
+            .collect();
+        assert_eq!(factors, vec![4, 3, 5]);
+    }
+
+    #[proptest]
+    fn agrees_with_the_rules(n: u64, d: u8) {
+        let trace = explain(n, d.into());
+        assert_eq!(trace.n(), n);
+        assert_eq!(trace.divisor(), u64::from(d));
+        assert_eq!(trace.divisible(), divisible_by(n, d.into()));
+    }
+
+    #[proptest]
+    fn agrees_with_the_rules_for_large_divisors(n: u64, d: u32) {
+        let d = u64::from(d) + 1;
+        assert_eq!(explain(n, d).divisible(), divisible_by(n, d));
+    }
+
+    #[proptest]
+    fn ends_with_the_verdict(n: u64, d: u8) {
+        let trace = explain(n, d.into());
+        match trace.steps().last() {
+            Some(Step::Lookup { divisible, .. }) => assert_eq!(*divisible, trace.divisible()),
+            Some(Step::Factor(factor)) => assert_eq!(factor.divisible(), trace.divisible()),
+            Some(step) => panic!("unexpected last step {step:?}"),
+            None => assert!(d <= 1),
+        }
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_explain() {
+        let n: u8 = kani::any();
+        let d: u8 = kani::any();
+        kani::assume(d <= 12);
+        assert_eq!(
+            explain(n.into(), d.into()).divisible(),
+            n.checked_rem(d) == Some(0)
+        );
+    }
+}
@@ -0,0 +1,4974 @@
+//! Divisibility rules for the numbers 13 through 100.
+//!
+//! Numbers that are coprime to ten use an osculator, i.e. the generalisation
+//! of [`divisible_by_7`](crate::divisible_by_7) where the last digit is
+//! multiplied by a divisor specific multiplier and added to the rest of the
+//! number. Powers of two and five look at the last digits, see
+//! [`divisible_by_pow2`](crate::divisible_by_pow2), and all other numbers
+//! combine the rules of their coprime factors.
+
+use crate::utils::osculate;
+use crate::{
+    divisible_by_11, divisible_by_2, divisible_by_3, divisible_by_4, divisible_by_5,
+    divisible_by_7, divisible_by_8, divisible_by_9, divisible_by_pow2, divisible_by_pow5,
+};
+
+/// Determines if the provided number is divisible by thirteen (13).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_13};
+/// assert!(divisible_by_13(39));
+/// assert!(!divisible_by_13(40));
+/// ```
+pub const fn divisible_by_13(n: u64) -> bool {
+    matches!(osculate(n, 4), 0 | 13 | 26 | 39)
+}
+
+/// Determines if the provided number is divisible by fourteen (14).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_14};
+/// assert!(divisible_by_14(42));
+/// assert!(!divisible_by_14(43));
+/// ```
+pub const fn divisible_by_14(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_7(n)
+}
+
+/// Determines if the provided number is divisible by fifteen (15).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_15};
+/// assert!(divisible_by_15(45));
+/// assert!(!divisible_by_15(46));
+/// ```
+pub const fn divisible_by_15(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_5(n)
+}
+
+/// Determines if the provided number is divisible by sixteen (16).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_16};
+/// assert!(divisible_by_16(48));
+/// assert!(!divisible_by_16(49));
+/// ```
+pub const fn divisible_by_16(n: u64) -> bool {
+    divisible_by_pow2(n, 4)
+}
+
+/// Determines if the provided number is divisible by seventeen (17).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_17};
+/// assert!(divisible_by_17(51));
+/// assert!(!divisible_by_17(52));
+/// ```
+pub const fn divisible_by_17(n: u64) -> bool {
+    matches!(osculate(n, 12), 0 | 17 | 34 | 51 | 68 | 85 | 102 | 119)
+}
+
+/// Determines if the provided number is divisible by eighteen (18).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_18};
+/// assert!(divisible_by_18(54));
+/// assert!(!divisible_by_18(55));
+/// ```
+pub const fn divisible_by_18(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_9(n)
+}
+
+/// Determines if the provided number is divisible by nineteen (19).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_19};
+/// assert!(divisible_by_19(57));
+/// assert!(!divisible_by_19(58));
+/// ```
+pub const fn divisible_by_19(n: u64) -> bool {
+    matches!(osculate(n, 2), 0 | 19)
+}
+
+/// Determines if the provided number is divisible by twenty (20).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_20};
+/// assert!(divisible_by_20(60));
+/// assert!(!divisible_by_20(61));
+/// ```
+pub const fn divisible_by_20(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_5(n)
+}
+
+/// Determines if the provided number is divisible by twenty-one (21).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_21};
+/// assert!(divisible_by_21(63));
+/// assert!(!divisible_by_21(64));
+/// ```
+pub const fn divisible_by_21(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_7(n)
+}
+
+/// Determines if the provided number is divisible by twenty-two (22).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_22};
+/// assert!(divisible_by_22(66));
+/// assert!(!divisible_by_22(67));
+/// ```
+pub const fn divisible_by_22(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by twenty-three (23).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_23};
+/// assert!(divisible_by_23(69));
+/// assert!(!divisible_by_23(70));
+/// ```
+pub const fn divisible_by_23(n: u64) -> bool {
+    matches!(osculate(n, 7), 0 | 23 | 46 | 69)
+}
+
+/// Determines if the provided number is divisible by twenty-four (24).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_24};
+/// assert!(divisible_by_24(72));
+/// assert!(!divisible_by_24(73));
+/// ```
+pub const fn divisible_by_24(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_8(n)
+}
+
+/// Determines if the provided number is divisible by twenty-five (25).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_25};
+/// assert!(divisible_by_25(75));
+/// assert!(!divisible_by_25(76));
+/// ```
+pub const fn divisible_by_25(n: u64) -> bool {
+    divisible_by_pow5(n, 2)
+}
+
+/// Determines if the provided number is divisible by twenty-six (26).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_26};
+/// assert!(divisible_by_26(78));
+/// assert!(!divisible_by_26(79));
+/// ```
+pub const fn divisible_by_26(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_13(n)
+}
+
+/// Determines if the provided number is divisible by twenty-seven (27).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_27};
+/// assert!(divisible_by_27(81));
+/// assert!(!divisible_by_27(82));
+/// ```
+pub const fn divisible_by_27(n: u64) -> bool {
+    matches!(osculate(n, 19), 0 | 27 | 54 | 81 | 108 | 135 | 162 | 189)
+}
+
+/// Determines if the provided number is divisible by twenty-eight (28).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_28};
+/// assert!(divisible_by_28(84));
+/// assert!(!divisible_by_28(85));
+/// ```
+pub const fn divisible_by_28(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_7(n)
+}
+
+/// Determines if the provided number is divisible by twenty-nine (29).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_29};
+/// assert!(divisible_by_29(87));
+/// assert!(!divisible_by_29(88));
+/// ```
+pub const fn divisible_by_29(n: u64) -> bool {
+    matches!(osculate(n, 3), 0 | 29)
+}
+
+/// Determines if the provided number is divisible by thirty (30).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_30};
+/// assert!(divisible_by_30(90));
+/// assert!(!divisible_by_30(91));
+/// ```
+pub const fn divisible_by_30(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_3(n) && divisible_by_5(n)
+}
+
+/// Determines if the provided number is divisible by thirty-one (31).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_31};
+/// assert!(divisible_by_31(93));
+/// assert!(!divisible_by_31(94));
+/// ```
+pub const fn divisible_by_31(n: u64) -> bool {
+    matches!(
+        osculate(n, 28),
+        0 | 31 | 62 | 93 | 124 | 155 | 186 | 217 | 248 | 279
+    )
+}
+
+/// Determines if the provided number is divisible by thirty-two (32).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_32};
+/// assert!(divisible_by_32(96));
+/// assert!(!divisible_by_32(97));
+/// ```
+pub const fn divisible_by_32(n: u64) -> bool {
+    divisible_by_pow2(n, 5)
+}
+
+/// Determines if the provided number is divisible by thirty-three (33).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_33};
+/// assert!(divisible_by_33(99));
+/// assert!(!divisible_by_33(100));
+/// ```
+pub const fn divisible_by_33(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by thirty-four (34).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_34};
+/// assert!(divisible_by_34(102));
+/// assert!(!divisible_by_34(103));
+/// ```
+pub const fn divisible_by_34(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_17(n)
+}
+
+/// Determines if the provided number is divisible by thirty-five (35).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_35};
+/// assert!(divisible_by_35(105));
+/// assert!(!divisible_by_35(106));
+/// ```
+pub const fn divisible_by_35(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_7(n)
+}
+
+/// Determines if the provided number is divisible by thirty-six (36).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_36};
+/// assert!(divisible_by_36(108));
+/// assert!(!divisible_by_36(109));
+/// ```
+pub const fn divisible_by_36(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_9(n)
+}
+
+/// Determines if the provided number is divisible by thirty-seven (37).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_37};
+/// assert!(divisible_by_37(111));
+/// assert!(!divisible_by_37(112));
+/// ```
+pub const fn divisible_by_37(n: u64) -> bool {
+    matches!(osculate(n, 26), 0 | 37 | 74 | 111 | 148 | 185 | 222 | 259)
+}
+
+/// Determines if the provided number is divisible by thirty-eight (38).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_38};
+/// assert!(divisible_by_38(114));
+/// assert!(!divisible_by_38(115));
+/// ```
+pub const fn divisible_by_38(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_19(n)
+}
+
+/// Determines if the provided number is divisible by thirty-nine (39).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_39};
+/// assert!(divisible_by_39(117));
+/// assert!(!divisible_by_39(118));
+/// ```
+pub const fn divisible_by_39(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_13(n)
+}
+
+/// Determines if the provided number is divisible by forty (40).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_40};
+/// assert!(divisible_by_40(120));
+/// assert!(!divisible_by_40(121));
+/// ```
+pub const fn divisible_by_40(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_8(n)
+}
+
+/// Determines if the provided number is divisible by forty-one (41).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_41};
+/// assert!(divisible_by_41(123));
+/// assert!(!divisible_by_41(124));
+/// ```
+pub const fn divisible_by_41(n: u64) -> bool {
+    matches!(
+        osculate(n, 37),
+        0 | 41 | 82 | 123 | 164 | 205 | 246 | 287 | 328 | 369
+    )
+}
+
+/// Determines if the provided number is divisible by forty-two (42).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_42};
+/// assert!(divisible_by_42(126));
+/// assert!(!divisible_by_42(127));
+/// ```
+pub const fn divisible_by_42(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_3(n) && divisible_by_7(n)
+}
+
+/// Determines if the provided number is divisible by forty-three (43).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_43};
+/// assert!(divisible_by_43(129));
+/// assert!(!divisible_by_43(130));
+/// ```
+pub const fn divisible_by_43(n: u64) -> bool {
+    matches!(osculate(n, 13), 0 | 43 | 86 | 129)
+}
+
+/// Determines if the provided number is divisible by forty-four (44).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_44};
+/// assert!(divisible_by_44(132));
+/// assert!(!divisible_by_44(133));
+/// ```
+pub const fn divisible_by_44(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by forty-five (45).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_45};
+/// assert!(divisible_by_45(135));
+/// assert!(!divisible_by_45(136));
+/// ```
+pub const fn divisible_by_45(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_9(n)
+}
+
+/// Determines if the provided number is divisible by forty-six (46).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_46};
+/// assert!(divisible_by_46(138));
+/// assert!(!divisible_by_46(139));
+/// ```
+pub const fn divisible_by_46(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_23(n)
+}
+
+/// Determines if the provided number is divisible by forty-seven (47).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_47};
+/// assert!(divisible_by_47(141));
+/// assert!(!divisible_by_47(142));
+/// ```
+pub const fn divisible_by_47(n: u64) -> bool {
+    matches!(osculate(n, 33), 0 | 47 | 94 | 141 | 188 | 235 | 282 | 329)
+}
+
+/// Determines if the provided number is divisible by forty-eight (48).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_48};
+/// assert!(divisible_by_48(144));
+/// assert!(!divisible_by_48(145));
+/// ```
+pub const fn divisible_by_48(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_16(n)
+}
+
+/// Determines if the provided number is divisible by forty-nine (49).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_49};
+/// assert!(divisible_by_49(147));
+/// assert!(!divisible_by_49(148));
+/// ```
+pub const fn divisible_by_49(n: u64) -> bool {
+    matches!(osculate(n, 5), 0 | 49)
+}
+
+/// Determines if the provided number is divisible by fifty (50).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_50};
+/// assert!(divisible_by_50(150));
+/// assert!(!divisible_by_50(151));
+/// ```
+pub const fn divisible_by_50(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_25(n)
+}
+
+/// Determines if the provided number is divisible by fifty-one (51).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_51};
+/// assert!(divisible_by_51(153));
+/// assert!(!divisible_by_51(154));
+/// ```
+pub const fn divisible_by_51(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_17(n)
+}
+
+/// Determines if the provided number is divisible by fifty-two (52).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_52};
+/// assert!(divisible_by_52(156));
+/// assert!(!divisible_by_52(157));
+/// ```
+pub const fn divisible_by_52(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_13(n)
+}
+
+/// Determines if the provided number is divisible by fifty-three (53).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_53};
+/// assert!(divisible_by_53(159));
+/// assert!(!divisible_by_53(160));
+/// ```
+pub const fn divisible_by_53(n: u64) -> bool {
+    matches!(osculate(n, 16), 0 | 53 | 106 | 159)
+}
+
+/// Determines if the provided number is divisible by fifty-four (54).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_54};
+/// assert!(divisible_by_54(162));
+/// assert!(!divisible_by_54(163));
+/// ```
+pub const fn divisible_by_54(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_27(n)
+}
+
+/// Determines if the provided number is divisible by fifty-five (55).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_55};
+/// assert!(divisible_by_55(165));
+/// assert!(!divisible_by_55(166));
+/// ```
+pub const fn divisible_by_55(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by fifty-six (56).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_56};
+/// assert!(divisible_by_56(168));
+/// assert!(!divisible_by_56(169));
+/// ```
+pub const fn divisible_by_56(n: u64) -> bool {
+    divisible_by_7(n) && divisible_by_8(n)
+}
+
+/// Determines if the provided number is divisible by fifty-seven (57).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_57};
+/// assert!(divisible_by_57(171));
+/// assert!(!divisible_by_57(172));
+/// ```
+pub const fn divisible_by_57(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_19(n)
+}
+
+/// Determines if the provided number is divisible by fifty-eight (58).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_58};
+/// assert!(divisible_by_58(174));
+/// assert!(!divisible_by_58(175));
+/// ```
+pub const fn divisible_by_58(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_29(n)
+}
+
+/// Determines if the provided number is divisible by fifty-nine (59).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_59};
+/// assert!(divisible_by_59(177));
+/// assert!(!divisible_by_59(178));
+/// ```
+pub const fn divisible_by_59(n: u64) -> bool {
+    matches!(osculate(n, 6), 0 | 59)
+}
+
+/// Determines if the provided number is divisible by sixty (60).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_60};
+/// assert!(divisible_by_60(180));
+/// assert!(!divisible_by_60(181));
+/// ```
+pub const fn divisible_by_60(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_4(n) && divisible_by_5(n)
+}
+
+/// Determines if the provided number is divisible by sixty-one (61).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_61};
+/// assert!(divisible_by_61(183));
+/// assert!(!divisible_by_61(184));
+/// ```
+pub const fn divisible_by_61(n: u64) -> bool {
+    matches!(
+        osculate(n, 55),
+        0 | 61 | 122 | 183 | 244 | 305 | 366 | 427 | 488 | 549
+    )
+}
+
+/// Determines if the provided number is divisible by sixty-two (62).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_62};
+/// assert!(divisible_by_62(186));
+/// assert!(!divisible_by_62(187));
+/// ```
+pub const fn divisible_by_62(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_31(n)
+}
+
+/// Determines if the provided number is divisible by sixty-three (63).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_63};
+/// assert!(divisible_by_63(189));
+/// assert!(!divisible_by_63(190));
+/// ```
+pub const fn divisible_by_63(n: u64) -> bool {
+    divisible_by_7(n) && divisible_by_9(n)
+}
+
+/// Determines if the provided number is divisible by sixty-four (64).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_64};
+/// assert!(divisible_by_64(192));
+/// assert!(!divisible_by_64(193));
+/// ```
+pub const fn divisible_by_64(n: u64) -> bool {
+    divisible_by_pow2(n, 6)
+}
+
+/// Determines if the provided number is divisible by sixty-five (65).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_65};
+/// assert!(divisible_by_65(195));
+/// assert!(!divisible_by_65(196));
+/// ```
+pub const fn divisible_by_65(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_13(n)
+}
+
+/// Determines if the provided number is divisible by sixty-six (66).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_66};
+/// assert!(divisible_by_66(198));
+/// assert!(!divisible_by_66(199));
+/// ```
+pub const fn divisible_by_66(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_3(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by sixty-seven (67).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_67};
+/// assert!(divisible_by_67(201));
+/// assert!(!divisible_by_67(202));
+/// ```
+pub const fn divisible_by_67(n: u64) -> bool {
+    matches!(osculate(n, 47), 0 | 67 | 134 | 201 | 268 | 335 | 402 | 469)
+}
+
+/// Determines if the provided number is divisible by sixty-eight (68).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_68};
+/// assert!(divisible_by_68(204));
+/// assert!(!divisible_by_68(205));
+/// ```
+pub const fn divisible_by_68(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_17(n)
+}
+
+/// Determines if the provided number is divisible by sixty-nine (69).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_69};
+/// assert!(divisible_by_69(207));
+/// assert!(!divisible_by_69(208));
+/// ```
+pub const fn divisible_by_69(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_23(n)
+}
+
+/// Determines if the provided number is divisible by seventy (70).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_70};
+/// assert!(divisible_by_70(210));
+/// assert!(!divisible_by_70(211));
+/// ```
+pub const fn divisible_by_70(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_5(n) && divisible_by_7(n)
+}
+
+/// Determines if the provided number is divisible by seventy-one (71).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_71};
+/// assert!(divisible_by_71(213));
+/// assert!(!divisible_by_71(214));
+/// ```
+pub const fn divisible_by_71(n: u64) -> bool {
+    matches!(
+        osculate(n, 64),
+        0 | 71 | 142 | 213 | 284 | 355 | 426 | 497 | 568 | 639
+    )
+}
+
+/// Determines if the provided number is divisible by seventy-two (72).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_72};
+/// assert!(divisible_by_72(216));
+/// assert!(!divisible_by_72(217));
+/// ```
+pub const fn divisible_by_72(n: u64) -> bool {
+    divisible_by_8(n) && divisible_by_9(n)
+}
+
+/// Determines if the provided number is divisible by seventy-three (73).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_73};
+/// assert!(divisible_by_73(219));
+/// assert!(!divisible_by_73(220));
+/// ```
+pub const fn divisible_by_73(n: u64) -> bool {
+    matches!(osculate(n, 22), 0 | 73 | 146 | 219)
+}
+
+/// Determines if the provided number is divisible by seventy-four (74).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_74};
+/// assert!(divisible_by_74(222));
+/// assert!(!divisible_by_74(223));
+/// ```
+pub const fn divisible_by_74(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_37(n)
+}
+
+/// Determines if the provided number is divisible by seventy-five (75).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_75};
+/// assert!(divisible_by_75(225));
+/// assert!(!divisible_by_75(226));
+/// ```
+pub const fn divisible_by_75(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_25(n)
+}
+
+/// Determines if the provided number is divisible by seventy-six (76).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_76};
+/// assert!(divisible_by_76(228));
+/// assert!(!divisible_by_76(229));
+/// ```
+pub const fn divisible_by_76(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_19(n)
+}
+
+/// Determines if the provided number is divisible by seventy-seven (77).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_77};
+/// assert!(divisible_by_77(231));
+/// assert!(!divisible_by_77(232));
+/// ```
+pub const fn divisible_by_77(n: u64) -> bool {
+    divisible_by_7(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by seventy-eight (78).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_78};
+/// assert!(divisible_by_78(234));
+/// assert!(!divisible_by_78(235));
+/// ```
+pub const fn divisible_by_78(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_3(n) && divisible_by_13(n)
+}
+
+/// Determines if the provided number is divisible by seventy-nine (79).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_79};
+/// assert!(divisible_by_79(237));
+/// assert!(!divisible_by_79(238));
+/// ```
+pub const fn divisible_by_79(n: u64) -> bool {
+    matches!(osculate(n, 8), 0 | 79)
+}
+
+/// Determines if the provided number is divisible by eighty (80).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_80};
+/// assert!(divisible_by_80(240));
+/// assert!(!divisible_by_80(241));
+/// ```
+pub const fn divisible_by_80(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_16(n)
+}
+
+/// Determines if the provided number is divisible by eighty-one (81).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_81};
+/// assert!(divisible_by_81(243));
+/// assert!(!divisible_by_81(244));
+/// ```
+pub const fn divisible_by_81(n: u64) -> bool {
+    matches!(
+        osculate(n, 73),
+        0 | 81 | 162 | 243 | 324 | 405 | 486 | 567 | 648 | 729
+    )
+}
+
+/// Determines if the provided number is divisible by eighty-two (82).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_82};
+/// assert!(divisible_by_82(246));
+/// assert!(!divisible_by_82(247));
+/// ```
+pub const fn divisible_by_82(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_41(n)
+}
+
+/// Determines if the provided number is divisible by eighty-three (83).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_83};
+/// assert!(divisible_by_83(249));
+/// assert!(!divisible_by_83(250));
+/// ```
+pub const fn divisible_by_83(n: u64) -> bool {
+    matches!(osculate(n, 25), 0 | 83 | 166 | 249)
+}
+
+/// Determines if the provided number is divisible by eighty-four (84).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_84};
+/// assert!(divisible_by_84(252));
+/// assert!(!divisible_by_84(253));
+/// ```
+pub const fn divisible_by_84(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_4(n) && divisible_by_7(n)
+}
+
+/// Determines if the provided number is divisible by eighty-five (85).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_85};
+/// assert!(divisible_by_85(255));
+/// assert!(!divisible_by_85(256));
+/// ```
+pub const fn divisible_by_85(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_17(n)
+}
+
+/// Determines if the provided number is divisible by eighty-six (86).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_86};
+/// assert!(divisible_by_86(258));
+/// assert!(!divisible_by_86(259));
+/// ```
+pub const fn divisible_by_86(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_43(n)
+}
+
+/// Determines if the provided number is divisible by eighty-seven (87).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_87};
+/// assert!(divisible_by_87(261));
+/// assert!(!divisible_by_87(262));
+/// ```
+pub const fn divisible_by_87(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_29(n)
+}
+
+/// Determines if the provided number is divisible by eighty-eight (88).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_88};
+/// assert!(divisible_by_88(264));
+/// assert!(!divisible_by_88(265));
+/// ```
+pub const fn divisible_by_88(n: u64) -> bool {
+    divisible_by_8(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by eighty-nine (89).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_89};
+/// assert!(divisible_by_89(267));
+/// assert!(!divisible_by_89(268));
+/// ```
+pub const fn divisible_by_89(n: u64) -> bool {
+    matches!(osculate(n, 9), 0 | 89)
+}
+
+/// Determines if the provided number is divisible by ninety (90).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_90};
+/// assert!(divisible_by_90(270));
+/// assert!(!divisible_by_90(271));
+/// ```
+pub const fn divisible_by_90(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_5(n) && divisible_by_9(n)
+}
+
+/// Determines if the provided number is divisible by ninety-one (91).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_91};
+/// assert!(divisible_by_91(273));
+/// assert!(!divisible_by_91(274));
+/// ```
+pub const fn divisible_by_91(n: u64) -> bool {
+    divisible_by_7(n) && divisible_by_13(n)
+}
+
+/// Determines if the provided number is divisible by ninety-two (92).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_92};
+/// assert!(divisible_by_92(276));
+/// assert!(!divisible_by_92(277));
+/// ```
+pub const fn divisible_by_92(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_23(n)
+}
+
+/// Determines if the provided number is divisible by ninety-three (93).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_93};
+/// assert!(divisible_by_93(279));
+/// assert!(!divisible_by_93(280));
+/// ```
+pub const fn divisible_by_93(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_31(n)
+}
+
+/// Determines if the provided number is divisible by ninety-four (94).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_94};
+/// assert!(divisible_by_94(282));
+/// assert!(!divisible_by_94(283));
+/// ```
+pub const fn divisible_by_94(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_47(n)
+}
+
+/// Determines if the provided number is divisible by ninety-five (95).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_95};
+/// assert!(divisible_by_95(285));
+/// assert!(!divisible_by_95(286));
+/// ```
+pub const fn divisible_by_95(n: u64) -> bool {
+    divisible_by_5(n) && divisible_by_19(n)
+}
+
+/// Determines if the provided number is divisible by ninety-six (96).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_96};
+/// assert!(divisible_by_96(288));
+/// assert!(!divisible_by_96(289));
+/// ```
+pub const fn divisible_by_96(n: u64) -> bool {
+    divisible_by_3(n) && divisible_by_32(n)
+}
+
+/// Determines if the provided number is divisible by ninety-seven (97).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_97};
+/// assert!(divisible_by_97(291));
+/// assert!(!divisible_by_97(292));
+/// ```
+pub const fn divisible_by_97(n: u64) -> bool {
+    matches!(osculate(n, 68), 0 | 97 | 194 | 291 | 388 | 485 | 582 | 679)
+}
+
+/// Determines if the provided number is divisible by ninety-eight (98).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_98};
+/// assert!(divisible_by_98(294));
+/// assert!(!divisible_by_98(295));
+/// ```
+pub const fn divisible_by_98(n: u64) -> bool {
+    divisible_by_2(n) && divisible_by_49(n)
+}
+
+/// Determines if the provided number is divisible by ninety-nine (99).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_99};
+/// assert!(divisible_by_99(297));
+/// assert!(!divisible_by_99(298));
+/// ```
+pub const fn divisible_by_99(n: u64) -> bool {
+    divisible_by_9(n) && divisible_by_11(n)
+}
+
+/// Determines if the provided number is divisible by one hundred (100).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_100};
+/// assert!(divisible_by_100(300));
+/// assert!(!divisible_by_100(301));
+/// ```
+pub const fn divisible_by_100(n: u64) -> bool {
+    divisible_by_4(n) && divisible_by_25(n)
+}
+
+/// Routes the provided divisor from thirteen (13) through one hundred (100) to
+/// its rule, as a `match` so that it can be evaluated at compile time.
+pub(crate) const fn divisible_by_13_to_100(n: u64, d: u64) -> bool {
+    match d {
+        13 => divisible_by_13(n),
+        14 => divisible_by_14(n),
+        15 => divisible_by_15(n),
+        16 => divisible_by_16(n),
+        17 => divisible_by_17(n),
+        18 => divisible_by_18(n),
+        19 => divisible_by_19(n),
+        20 => divisible_by_20(n),
+        21 => divisible_by_21(n),
+        22 => divisible_by_22(n),
+        23 => divisible_by_23(n),
+        24 => divisible_by_24(n),
+        25 => divisible_by_25(n),
+        26 => divisible_by_26(n),
+        27 => divisible_by_27(n),
+        28 => divisible_by_28(n),
+        29 => divisible_by_29(n),
+        30 => divisible_by_30(n),
+        31 => divisible_by_31(n),
+        32 => divisible_by_32(n),
+        33 => divisible_by_33(n),
+        34 => divisible_by_34(n),
+        35 => divisible_by_35(n),
+        36 => divisible_by_36(n),
+        37 => divisible_by_37(n),
+        38 => divisible_by_38(n),
+        39 => divisible_by_39(n),
+        40 => divisible_by_40(n),
+        41 => divisible_by_41(n),
+        42 => divisible_by_42(n),
+        43 => divisible_by_43(n),
+        44 => divisible_by_44(n),
+        45 => divisible_by_45(n),
+        46 => divisible_by_46(n),
+        47 => divisible_by_47(n),
+        48 => divisible_by_48(n),
+        49 => divisible_by_49(n),
+        50 => divisible_by_50(n),
+        51 => divisible_by_51(n),
+        52 => divisible_by_52(n),
+        53 => divisible_by_53(n),
+        54 => divisible_by_54(n),
+        55 => divisible_by_55(n),
+        56 => divisible_by_56(n),
+        57 => divisible_by_57(n),
+        58 => divisible_by_58(n),
+        59 => divisible_by_59(n),
+        60 => divisible_by_60(n),
+        61 => divisible_by_61(n),
+        62 => divisible_by_62(n),
+        63 => divisible_by_63(n),
+        64 => divisible_by_64(n),
+        65 => divisible_by_65(n),
+        66 => divisible_by_66(n),
+        67 => divisible_by_67(n),
+        68 => divisible_by_68(n),
+        69 => divisible_by_69(n),
+        70 => divisible_by_70(n),
+        71 => divisible_by_71(n),
+        72 => divisible_by_72(n),
+        73 => divisible_by_73(n),
+        74 => divisible_by_74(n),
+        75 => divisible_by_75(n),
+        76 => divisible_by_76(n),
+        77 => divisible_by_77(n),
+        78 => divisible_by_78(n),
+        79 => divisible_by_79(n),
+        80 => divisible_by_80(n),
+        81 => divisible_by_81(n),
+        82 => divisible_by_82(n),
+        83 => divisible_by_83(n),
+        84 => divisible_by_84(n),
+        85 => divisible_by_85(n),
+        86 => divisible_by_86(n),
+        87 => divisible_by_87(n),
+        88 => divisible_by_88(n),
+        89 => divisible_by_89(n),
+        90 => divisible_by_90(n),
+        91 => divisible_by_91(n),
+        92 => divisible_by_92(n),
+        93 => divisible_by_93(n),
+        94 => divisible_by_94(n),
+        95 => divisible_by_95(n),
+        96 => divisible_by_96(n),
+        97 => divisible_by_97(n),
+        98 => divisible_by_98(n),
+        99 => divisible_by_99(n),
+        100 => divisible_by_100(n),
+        _ => unreachable!(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1)]
+    #[case(12)]
+    #[case(92)]
+    #[case(18446744073709551612)]
+    fn is_not_divisible_by_13(#[case] n: u64) {
+        let result = divisible_by_13(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(13)]
+    #[case(481)]
+    #[case(18446744073709551613)]
+    fn is_divisible_by_13(#[case] n: u64) {
+        let result = divisible_by_13(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_13(base: u32) {
+        let n = 13 * (base as u64);
+        for i in 1..13 {
+            assert!(!divisible_by_13(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_13(base: u32) {
+        let n = 13 * (base as u64);
+        let result = divisible_by_13(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(13)]
+    #[case(99)]
+    #[case(18446744073709551613)]
+    fn is_not_divisible_by_14(#[case] n: u64) {
+        let result = divisible_by_14(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(14)]
+    #[case(518)]
+    #[case(18446744073709551614)]
+    fn is_divisible_by_14(#[case] n: u64) {
+        let result = divisible_by_14(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_14(base: u32) {
+        let n = 14 * (base as u64);
+        for i in 1..14 {
+            assert!(!divisible_by_14(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_14(base: u32) {
+        let n = 14 * (base as u64);
+        let result = divisible_by_14(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(14)]
+    #[case(106)]
+    #[case(18446744073709551614)]
+    fn is_not_divisible_by_15(#[case] n: u64) {
+        let result = divisible_by_15(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(15)]
+    #[case(555)]
+    #[case(18446744073709551615)]
+    fn is_divisible_by_15(#[case] n: u64) {
+        let result = divisible_by_15(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_15(base: u32) {
+        let n = 15 * (base as u64);
+        for i in 1..15 {
+            assert!(!divisible_by_15(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_15(base: u32) {
+        let n = 15 * (base as u64);
+        let result = divisible_by_15(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(15)]
+    #[case(113)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_16(#[case] n: u64) {
+        let result = divisible_by_16(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(16)]
+    #[case(592)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_16(#[case] n: u64) {
+        let result = divisible_by_16(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_16(base: u32) {
+        let n = 16 * (base as u64);
+        for i in 1..16 {
+            assert!(!divisible_by_16(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_16(base: u32) {
+        let n = 16 * (base as u64);
+        let result = divisible_by_16(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(16)]
+    #[case(120)]
+    #[case(18446744073709551614)]
+    fn is_not_divisible_by_17(#[case] n: u64) {
+        let result = divisible_by_17(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(17)]
+    #[case(629)]
+    #[case(18446744073709551615)]
+    fn is_divisible_by_17(#[case] n: u64) {
+        let result = divisible_by_17(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_17(base: u32) {
+        let n = 17 * (base as u64);
+        for i in 1..17 {
+            assert!(!divisible_by_17(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_17(base: u32) {
+        let n = 17 * (base as u64);
+        let result = divisible_by_17(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(17)]
+    #[case(127)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_18(#[case] n: u64) {
+        let result = divisible_by_18(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(18)]
+    #[case(666)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_18(#[case] n: u64) {
+        let result = divisible_by_18(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_18(base: u32) {
+        let n = 18 * (base as u64);
+        for i in 1..18 {
+            assert!(!divisible_by_18(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_18(base: u32) {
+        let n = 18 * (base as u64);
+        let result = divisible_by_18(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(18)]
+    #[case(134)]
+    #[case(18446744073709551598)]
+    fn is_not_divisible_by_19(#[case] n: u64) {
+        let result = divisible_by_19(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(19)]
+    #[case(703)]
+    #[case(18446744073709551599)]
+    fn is_divisible_by_19(#[case] n: u64) {
+        let result = divisible_by_19(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_19(base: u32) {
+        let n = 19 * (base as u64);
+        for i in 1..19 {
+            assert!(!divisible_by_19(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_19(base: u32) {
+        let n = 19 * (base as u64);
+        let result = divisible_by_19(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(19)]
+    #[case(141)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_20(#[case] n: u64) {
+        let result = divisible_by_20(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(20)]
+    #[case(740)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_20(#[case] n: u64) {
+        let result = divisible_by_20(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_20(base: u32) {
+        let n = 20 * (base as u64);
+        for i in 1..20 {
+            assert!(!divisible_by_20(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_20(base: u32) {
+        let n = 20 * (base as u64);
+        let result = divisible_by_20(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(20)]
+    #[case(148)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_21(#[case] n: u64) {
+        let result = divisible_by_21(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(21)]
+    #[case(777)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_21(#[case] n: u64) {
+        let result = divisible_by_21(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_21(base: u32) {
+        let n = 21 * (base as u64);
+        for i in 1..21 {
+            assert!(!divisible_by_21(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_21(base: u32) {
+        let n = 21 * (base as u64);
+        let result = divisible_by_21(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(21)]
+    #[case(155)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_22(#[case] n: u64) {
+        let result = divisible_by_22(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(22)]
+    #[case(814)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_22(#[case] n: u64) {
+        let result = divisible_by_22(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_22(base: u32) {
+        let n = 22 * (base as u64);
+        for i in 1..22 {
+            assert!(!divisible_by_22(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_22(base: u32) {
+        let n = 22 * (base as u64);
+        let result = divisible_by_22(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(22)]
+    #[case(162)]
+    #[case(18446744073709551609)]
+    fn is_not_divisible_by_23(#[case] n: u64) {
+        let result = divisible_by_23(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(23)]
+    #[case(851)]
+    #[case(18446744073709551610)]
+    fn is_divisible_by_23(#[case] n: u64) {
+        let result = divisible_by_23(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_23(base: u32) {
+        let n = 23 * (base as u64);
+        for i in 1..23 {
+            assert!(!divisible_by_23(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_23(base: u32) {
+        let n = 23 * (base as u64);
+        let result = divisible_by_23(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(23)]
+    #[case(169)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_24(#[case] n: u64) {
+        let result = divisible_by_24(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(24)]
+    #[case(888)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_24(#[case] n: u64) {
+        let result = divisible_by_24(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_24(base: u32) {
+        let n = 24 * (base as u64);
+        for i in 1..24 {
+            assert!(!divisible_by_24(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_24(base: u32) {
+        let n = 24 * (base as u64);
+        let result = divisible_by_24(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(24)]
+    #[case(176)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_25(#[case] n: u64) {
+        let result = divisible_by_25(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(25)]
+    #[case(925)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_25(#[case] n: u64) {
+        let result = divisible_by_25(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_25(base: u32) {
+        let n = 25 * (base as u64);
+        for i in 1..25 {
+            assert!(!divisible_by_25(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_25(base: u32) {
+        let n = 25 * (base as u64);
+        let result = divisible_by_25(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(25)]
+    #[case(183)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_26(#[case] n: u64) {
+        let result = divisible_by_26(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(26)]
+    #[case(962)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_26(#[case] n: u64) {
+        let result = divisible_by_26(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_26(base: u32) {
+        let n = 26 * (base as u64);
+        for i in 1..26 {
+            assert!(!divisible_by_26(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_26(base: u32) {
+        let n = 26 * (base as u64);
+        let result = divisible_by_26(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(26)]
+    #[case(190)]
+    #[case(18446744073709551590)]
+    fn is_not_divisible_by_27(#[case] n: u64) {
+        let result = divisible_by_27(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(27)]
+    #[case(999)]
+    #[case(18446744073709551591)]
+    fn is_divisible_by_27(#[case] n: u64) {
+        let result = divisible_by_27(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_27(base: u32) {
+        let n = 27 * (base as u64);
+        for i in 1..27 {
+            assert!(!divisible_by_27(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_27(base: u32) {
+        let n = 27 * (base as u64);
+        let result = divisible_by_27(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(27)]
+    #[case(197)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_28(#[case] n: u64) {
+        let result = divisible_by_28(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(28)]
+    #[case(1036)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_28(#[case] n: u64) {
+        let result = divisible_by_28(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_28(base: u32) {
+        let n = 28 * (base as u64);
+        for i in 1..28 {
+            assert!(!divisible_by_28(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_28(base: u32) {
+        let n = 28 * (base as u64);
+        let result = divisible_by_28(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(28)]
+    #[case(204)]
+    #[case(18446744073709551591)]
+    fn is_not_divisible_by_29(#[case] n: u64) {
+        let result = divisible_by_29(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(29)]
+    #[case(1073)]
+    #[case(18446744073709551592)]
+    fn is_divisible_by_29(#[case] n: u64) {
+        let result = divisible_by_29(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_29(base: u32) {
+        let n = 29 * (base as u64);
+        for i in 1..29 {
+            assert!(!divisible_by_29(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_29(base: u32) {
+        let n = 29 * (base as u64);
+        let result = divisible_by_29(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(29)]
+    #[case(211)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_30(#[case] n: u64) {
+        let result = divisible_by_30(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(30)]
+    #[case(1110)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_30(#[case] n: u64) {
+        let result = divisible_by_30(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_30(base: u32) {
+        let n = 30 * (base as u64);
+        for i in 1..30 {
+            assert!(!divisible_by_30(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_30(base: u32) {
+        let n = 30 * (base as u64);
+        let result = divisible_by_30(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(30)]
+    #[case(218)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_31(#[case] n: u64) {
+        let result = divisible_by_31(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(31)]
+    #[case(1147)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_31(#[case] n: u64) {
+        let result = divisible_by_31(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_31(base: u32) {
+        let n = 31 * (base as u64);
+        for i in 1..31 {
+            assert!(!divisible_by_31(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_31(base: u32) {
+        let n = 31 * (base as u64);
+        let result = divisible_by_31(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(31)]
+    #[case(225)]
+    #[case(18446744073709551583)]
+    fn is_not_divisible_by_32(#[case] n: u64) {
+        let result = divisible_by_32(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(32)]
+    #[case(1184)]
+    #[case(18446744073709551584)]
+    fn is_divisible_by_32(#[case] n: u64) {
+        let result = divisible_by_32(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_32(base: u32) {
+        let n = 32 * (base as u64);
+        for i in 1..32 {
+            assert!(!divisible_by_32(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_32(base: u32) {
+        let n = 32 * (base as u64);
+        let result = divisible_by_32(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(32)]
+    #[case(232)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_33(#[case] n: u64) {
+        let result = divisible_by_33(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(33)]
+    #[case(1221)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_33(#[case] n: u64) {
+        let result = divisible_by_33(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_33(base: u32) {
+        let n = 33 * (base as u64);
+        for i in 1..33 {
+            assert!(!divisible_by_33(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_33(base: u32) {
+        let n = 33 * (base as u64);
+        let result = divisible_by_33(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(33)]
+    #[case(239)]
+    #[case(18446744073709551597)]
+    fn is_not_divisible_by_34(#[case] n: u64) {
+        let result = divisible_by_34(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(34)]
+    #[case(1258)]
+    #[case(18446744073709551598)]
+    fn is_divisible_by_34(#[case] n: u64) {
+        let result = divisible_by_34(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_34(base: u32) {
+        let n = 34 * (base as u64);
+        for i in 1..34 {
+            assert!(!divisible_by_34(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_34(base: u32) {
+        let n = 34 * (base as u64);
+        let result = divisible_by_34(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(34)]
+    #[case(246)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_35(#[case] n: u64) {
+        let result = divisible_by_35(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(35)]
+    #[case(1295)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_35(#[case] n: u64) {
+        let result = divisible_by_35(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_35(base: u32) {
+        let n = 35 * (base as u64);
+        for i in 1..35 {
+            assert!(!divisible_by_35(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_35(base: u32) {
+        let n = 35 * (base as u64);
+        let result = divisible_by_35(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(35)]
+    #[case(253)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_36(#[case] n: u64) {
+        let result = divisible_by_36(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(36)]
+    #[case(1332)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_36(#[case] n: u64) {
+        let result = divisible_by_36(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_36(base: u32) {
+        let n = 36 * (base as u64);
+        for i in 1..36 {
+            assert!(!divisible_by_36(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_36(base: u32) {
+        let n = 36 * (base as u64);
+        let result = divisible_by_36(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(36)]
+    #[case(260)]
+    #[case(18446744073709551603)]
+    fn is_not_divisible_by_37(#[case] n: u64) {
+        let result = divisible_by_37(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(37)]
+    #[case(1369)]
+    #[case(18446744073709551604)]
+    fn is_divisible_by_37(#[case] n: u64) {
+        let result = divisible_by_37(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_37(base: u32) {
+        let n = 37 * (base as u64);
+        for i in 1..37 {
+            assert!(!divisible_by_37(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_37(base: u32) {
+        let n = 37 * (base as u64);
+        let result = divisible_by_37(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(37)]
+    #[case(267)]
+    #[case(18446744073709551579)]
+    fn is_not_divisible_by_38(#[case] n: u64) {
+        let result = divisible_by_38(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(38)]
+    #[case(1406)]
+    #[case(18446744073709551580)]
+    fn is_divisible_by_38(#[case] n: u64) {
+        let result = divisible_by_38(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_38(base: u32) {
+        let n = 38 * (base as u64);
+        for i in 1..38 {
+            assert!(!divisible_by_38(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_38(base: u32) {
+        let n = 38 * (base as u64);
+        let result = divisible_by_38(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(38)]
+    #[case(274)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_39(#[case] n: u64) {
+        let result = divisible_by_39(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(39)]
+    #[case(1443)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_39(#[case] n: u64) {
+        let result = divisible_by_39(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_39(base: u32) {
+        let n = 39 * (base as u64);
+        for i in 1..39 {
+            assert!(!divisible_by_39(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_39(base: u32) {
+        let n = 39 * (base as u64);
+        let result = divisible_by_39(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(39)]
+    #[case(281)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_40(#[case] n: u64) {
+        let result = divisible_by_40(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(40)]
+    #[case(1480)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_40(#[case] n: u64) {
+        let result = divisible_by_40(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_40(base: u32) {
+        let n = 40 * (base as u64);
+        for i in 1..40 {
+            assert!(!divisible_by_40(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_40(base: u32) {
+        let n = 40 * (base as u64);
+        let result = divisible_by_40(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(40)]
+    #[case(288)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_41(#[case] n: u64) {
+        let result = divisible_by_41(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(41)]
+    #[case(1517)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_41(#[case] n: u64) {
+        let result = divisible_by_41(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_41(base: u32) {
+        let n = 41 * (base as u64);
+        for i in 1..41 {
+            assert!(!divisible_by_41(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_41(base: u32) {
+        let n = 41 * (base as u64);
+        let result = divisible_by_41(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(41)]
+    #[case(295)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_42(#[case] n: u64) {
+        let result = divisible_by_42(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(42)]
+    #[case(1554)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_42(#[case] n: u64) {
+        let result = divisible_by_42(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_42(base: u32) {
+        let n = 42 * (base as u64);
+        for i in 1..42 {
+            assert!(!divisible_by_42(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_42(base: u32) {
+        let n = 42 * (base as u64);
+        let result = divisible_by_42(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(42)]
+    #[case(302)]
+    #[case(18446744073709551574)]
+    fn is_not_divisible_by_43(#[case] n: u64) {
+        let result = divisible_by_43(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(43)]
+    #[case(1591)]
+    #[case(18446744073709551575)]
+    fn is_divisible_by_43(#[case] n: u64) {
+        let result = divisible_by_43(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_43(base: u32) {
+        let n = 43 * (base as u64);
+        for i in 1..43 {
+            assert!(!divisible_by_43(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_43(base: u32) {
+        let n = 43 * (base as u64);
+        let result = divisible_by_43(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(43)]
+    #[case(309)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_44(#[case] n: u64) {
+        let result = divisible_by_44(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(44)]
+    #[case(1628)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_44(#[case] n: u64) {
+        let result = divisible_by_44(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_44(base: u32) {
+        let n = 44 * (base as u64);
+        for i in 1..44 {
+            assert!(!divisible_by_44(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_44(base: u32) {
+        let n = 44 * (base as u64);
+        let result = divisible_by_44(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(44)]
+    #[case(316)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_45(#[case] n: u64) {
+        let result = divisible_by_45(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(45)]
+    #[case(1665)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_45(#[case] n: u64) {
+        let result = divisible_by_45(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_45(base: u32) {
+        let n = 45 * (base as u64);
+        for i in 1..45 {
+            assert!(!divisible_by_45(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_45(base: u32) {
+        let n = 45 * (base as u64);
+        let result = divisible_by_45(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(45)]
+    #[case(323)]
+    #[case(18446744073709551609)]
+    fn is_not_divisible_by_46(#[case] n: u64) {
+        let result = divisible_by_46(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(46)]
+    #[case(1702)]
+    #[case(18446744073709551610)]
+    fn is_divisible_by_46(#[case] n: u64) {
+        let result = divisible_by_46(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_46(base: u32) {
+        let n = 46 * (base as u64);
+        for i in 1..46 {
+            assert!(!divisible_by_46(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_46(base: u32) {
+        let n = 46 * (base as u64);
+        let result = divisible_by_46(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(46)]
+    #[case(330)]
+    #[case(18446744073709551590)]
+    fn is_not_divisible_by_47(#[case] n: u64) {
+        let result = divisible_by_47(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(47)]
+    #[case(1739)]
+    #[case(18446744073709551591)]
+    fn is_divisible_by_47(#[case] n: u64) {
+        let result = divisible_by_47(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_47(base: u32) {
+        let n = 47 * (base as u64);
+        for i in 1..47 {
+            assert!(!divisible_by_47(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_47(base: u32) {
+        let n = 47 * (base as u64);
+        let result = divisible_by_47(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(47)]
+    #[case(337)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_48(#[case] n: u64) {
+        let result = divisible_by_48(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(48)]
+    #[case(1776)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_48(#[case] n: u64) {
+        let result = divisible_by_48(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_48(base: u32) {
+        let n = 48 * (base as u64);
+        for i in 1..48 {
+            assert!(!divisible_by_48(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_48(base: u32) {
+        let n = 48 * (base as u64);
+        let result = divisible_by_48(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(48)]
+    #[case(344)]
+    #[case(18446744073709551613)]
+    fn is_not_divisible_by_49(#[case] n: u64) {
+        let result = divisible_by_49(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(49)]
+    #[case(1813)]
+    #[case(18446744073709551614)]
+    fn is_divisible_by_49(#[case] n: u64) {
+        let result = divisible_by_49(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_49(base: u32) {
+        let n = 49 * (base as u64);
+        for i in 1..49 {
+            assert!(!divisible_by_49(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_49(base: u32) {
+        let n = 49 * (base as u64);
+        let result = divisible_by_49(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(49)]
+    #[case(351)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_50(#[case] n: u64) {
+        let result = divisible_by_50(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(50)]
+    #[case(1850)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_50(#[case] n: u64) {
+        let result = divisible_by_50(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_50(base: u32) {
+        let n = 50 * (base as u64);
+        for i in 1..50 {
+            assert!(!divisible_by_50(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_50(base: u32) {
+        let n = 50 * (base as u64);
+        let result = divisible_by_50(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(50)]
+    #[case(358)]
+    #[case(18446744073709551614)]
+    fn is_not_divisible_by_51(#[case] n: u64) {
+        let result = divisible_by_51(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(51)]
+    #[case(1887)]
+    #[case(18446744073709551615)]
+    fn is_divisible_by_51(#[case] n: u64) {
+        let result = divisible_by_51(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_51(base: u32) {
+        let n = 51 * (base as u64);
+        for i in 1..51 {
+            assert!(!divisible_by_51(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_51(base: u32) {
+        let n = 51 * (base as u64);
+        let result = divisible_by_51(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(51)]
+    #[case(365)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_52(#[case] n: u64) {
+        let result = divisible_by_52(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(52)]
+    #[case(1924)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_52(#[case] n: u64) {
+        let result = divisible_by_52(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_52(base: u32) {
+        let n = 52 * (base as u64);
+        for i in 1..52 {
+            assert!(!divisible_by_52(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_52(base: u32) {
+        let n = 52 * (base as u64);
+        let result = divisible_by_52(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(52)]
+    #[case(372)]
+    #[case(18446744073709551600)]
+    fn is_not_divisible_by_53(#[case] n: u64) {
+        let result = divisible_by_53(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(53)]
+    #[case(1961)]
+    #[case(18446744073709551601)]
+    fn is_divisible_by_53(#[case] n: u64) {
+        let result = divisible_by_53(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_53(base: u32) {
+        let n = 53 * (base as u64);
+        for i in 1..53 {
+            assert!(!divisible_by_53(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_53(base: u32) {
+        let n = 53 * (base as u64);
+        let result = divisible_by_53(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(53)]
+    #[case(379)]
+    #[case(18446744073709551563)]
+    fn is_not_divisible_by_54(#[case] n: u64) {
+        let result = divisible_by_54(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(54)]
+    #[case(1998)]
+    #[case(18446744073709551564)]
+    fn is_divisible_by_54(#[case] n: u64) {
+        let result = divisible_by_54(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_54(base: u32) {
+        let n = 54 * (base as u64);
+        for i in 1..54 {
+            assert!(!divisible_by_54(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_54(base: u32) {
+        let n = 54 * (base as u64);
+        let result = divisible_by_54(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(54)]
+    #[case(386)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_55(#[case] n: u64) {
+        let result = divisible_by_55(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(55)]
+    #[case(2035)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_55(#[case] n: u64) {
+        let result = divisible_by_55(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_55(base: u32) {
+        let n = 55 * (base as u64);
+        for i in 1..55 {
+            assert!(!divisible_by_55(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_55(base: u32) {
+        let n = 55 * (base as u64);
+        let result = divisible_by_55(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(55)]
+    #[case(393)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_56(#[case] n: u64) {
+        let result = divisible_by_56(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(56)]
+    #[case(2072)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_56(#[case] n: u64) {
+        let result = divisible_by_56(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_56(base: u32) {
+        let n = 56 * (base as u64);
+        for i in 1..56 {
+            assert!(!divisible_by_56(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_56(base: u32) {
+        let n = 56 * (base as u64);
+        let result = divisible_by_56(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(56)]
+    #[case(400)]
+    #[case(18446744073709551560)]
+    fn is_not_divisible_by_57(#[case] n: u64) {
+        let result = divisible_by_57(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(57)]
+    #[case(2109)]
+    #[case(18446744073709551561)]
+    fn is_divisible_by_57(#[case] n: u64) {
+        let result = divisible_by_57(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_57(base: u32) {
+        let n = 57 * (base as u64);
+        for i in 1..57 {
+            assert!(!divisible_by_57(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_57(base: u32) {
+        let n = 57 * (base as u64);
+        let result = divisible_by_57(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(57)]
+    #[case(407)]
+    #[case(18446744073709551591)]
+    fn is_not_divisible_by_58(#[case] n: u64) {
+        let result = divisible_by_58(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(58)]
+    #[case(2146)]
+    #[case(18446744073709551592)]
+    fn is_divisible_by_58(#[case] n: u64) {
+        let result = divisible_by_58(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_58(base: u32) {
+        let n = 58 * (base as u64);
+        for i in 1..58 {
+            assert!(!divisible_by_58(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_58(base: u32) {
+        let n = 58 * (base as u64);
+        let result = divisible_by_58(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(58)]
+    #[case(414)]
+    #[case(18446744073709551610)]
+    fn is_not_divisible_by_59(#[case] n: u64) {
+        let result = divisible_by_59(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(59)]
+    #[case(2183)]
+    #[case(18446744073709551611)]
+    fn is_divisible_by_59(#[case] n: u64) {
+        let result = divisible_by_59(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_59(base: u32) {
+        let n = 59 * (base as u64);
+        for i in 1..59 {
+            assert!(!divisible_by_59(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_59(base: u32) {
+        let n = 59 * (base as u64);
+        let result = divisible_by_59(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(59)]
+    #[case(421)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_60(#[case] n: u64) {
+        let result = divisible_by_60(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(60)]
+    #[case(2220)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_60(#[case] n: u64) {
+        let result = divisible_by_60(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_60(base: u32) {
+        let n = 60 * (base as u64);
+        for i in 1..60 {
+            assert!(!divisible_by_60(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_60(base: u32) {
+        let n = 60 * (base as u64);
+        let result = divisible_by_60(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(60)]
+    #[case(428)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_61(#[case] n: u64) {
+        let result = divisible_by_61(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(61)]
+    #[case(2257)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_61(#[case] n: u64) {
+        let result = divisible_by_61(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_61(base: u32) {
+        let n = 61 * (base as u64);
+        for i in 1..61 {
+            assert!(!divisible_by_61(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_61(base: u32) {
+        let n = 61 * (base as u64);
+        let result = divisible_by_61(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(61)]
+    #[case(435)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_62(#[case] n: u64) {
+        let result = divisible_by_62(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(62)]
+    #[case(2294)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_62(#[case] n: u64) {
+        let result = divisible_by_62(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_62(base: u32) {
+        let n = 62 * (base as u64);
+        for i in 1..62 {
+            assert!(!divisible_by_62(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_62(base: u32) {
+        let n = 62 * (base as u64);
+        let result = divisible_by_62(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(62)]
+    #[case(442)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_63(#[case] n: u64) {
+        let result = divisible_by_63(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(63)]
+    #[case(2331)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_63(#[case] n: u64) {
+        let result = divisible_by_63(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_63(base: u32) {
+        let n = 63 * (base as u64);
+        for i in 1..63 {
+            assert!(!divisible_by_63(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_63(base: u32) {
+        let n = 63 * (base as u64);
+        let result = divisible_by_63(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(63)]
+    #[case(449)]
+    #[case(18446744073709551551)]
+    fn is_not_divisible_by_64(#[case] n: u64) {
+        let result = divisible_by_64(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(64)]
+    #[case(2368)]
+    #[case(18446744073709551552)]
+    fn is_divisible_by_64(#[case] n: u64) {
+        let result = divisible_by_64(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_64(base: u32) {
+        let n = 64 * (base as u64);
+        for i in 1..64 {
+            assert!(!divisible_by_64(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_64(base: u32) {
+        let n = 64 * (base as u64);
+        let result = divisible_by_64(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(64)]
+    #[case(456)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_65(#[case] n: u64) {
+        let result = divisible_by_65(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(65)]
+    #[case(2405)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_65(#[case] n: u64) {
+        let result = divisible_by_65(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_65(base: u32) {
+        let n = 65 * (base as u64);
+        for i in 1..65 {
+            assert!(!divisible_by_65(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_65(base: u32) {
+        let n = 65 * (base as u64);
+        let result = divisible_by_65(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(65)]
+    #[case(463)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_66(#[case] n: u64) {
+        let result = divisible_by_66(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(66)]
+    #[case(2442)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_66(#[case] n: u64) {
+        let result = divisible_by_66(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_66(base: u32) {
+        let n = 66 * (base as u64);
+        for i in 1..66 {
+            assert!(!divisible_by_66(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_66(base: u32) {
+        let n = 66 * (base as u64);
+        let result = divisible_by_66(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(66)]
+    #[case(470)]
+    #[case(18446744073709551598)]
+    fn is_not_divisible_by_67(#[case] n: u64) {
+        let result = divisible_by_67(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(67)]
+    #[case(2479)]
+    #[case(18446744073709551599)]
+    fn is_divisible_by_67(#[case] n: u64) {
+        let result = divisible_by_67(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_67(base: u32) {
+        let n = 67 * (base as u64);
+        for i in 1..67 {
+            assert!(!divisible_by_67(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_67(base: u32) {
+        let n = 67 * (base as u64);
+        let result = divisible_by_67(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(67)]
+    #[case(477)]
+    #[case(18446744073709551563)]
+    fn is_not_divisible_by_68(#[case] n: u64) {
+        let result = divisible_by_68(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(68)]
+    #[case(2516)]
+    #[case(18446744073709551564)]
+    fn is_divisible_by_68(#[case] n: u64) {
+        let result = divisible_by_68(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_68(base: u32) {
+        let n = 68 * (base as u64);
+        for i in 1..68 {
+            assert!(!divisible_by_68(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_68(base: u32) {
+        let n = 68 * (base as u64);
+        let result = divisible_by_68(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(68)]
+    #[case(484)]
+    #[case(18446744073709551563)]
+    fn is_not_divisible_by_69(#[case] n: u64) {
+        let result = divisible_by_69(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(69)]
+    #[case(2553)]
+    #[case(18446744073709551564)]
+    fn is_divisible_by_69(#[case] n: u64) {
+        let result = divisible_by_69(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_69(base: u32) {
+        let n = 69 * (base as u64);
+        for i in 1..69 {
+            assert!(!divisible_by_69(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_69(base: u32) {
+        let n = 69 * (base as u64);
+        let result = divisible_by_69(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(69)]
+    #[case(491)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_70(#[case] n: u64) {
+        let result = divisible_by_70(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(70)]
+    #[case(2590)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_70(#[case] n: u64) {
+        let result = divisible_by_70(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_70(base: u32) {
+        let n = 70 * (base as u64);
+        for i in 1..70 {
+            assert!(!divisible_by_70(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_70(base: u32) {
+        let n = 70 * (base as u64);
+        let result = divisible_by_70(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(70)]
+    #[case(498)]
+    #[case(18446744073709551605)]
+    fn is_not_divisible_by_71(#[case] n: u64) {
+        let result = divisible_by_71(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(71)]
+    #[case(2627)]
+    #[case(18446744073709551606)]
+    fn is_divisible_by_71(#[case] n: u64) {
+        let result = divisible_by_71(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_71(base: u32) {
+        let n = 71 * (base as u64);
+        for i in 1..71 {
+            assert!(!divisible_by_71(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_71(base: u32) {
+        let n = 71 * (base as u64);
+        let result = divisible_by_71(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(71)]
+    #[case(505)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_72(#[case] n: u64) {
+        let result = divisible_by_72(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(72)]
+    #[case(2664)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_72(#[case] n: u64) {
+        let result = divisible_by_72(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_72(base: u32) {
+        let n = 72 * (base as u64);
+        for i in 1..72 {
+            assert!(!divisible_by_72(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_72(base: u32) {
+        let n = 72 * (base as u64);
+        let result = divisible_by_72(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(72)]
+    #[case(512)]
+    #[case(18446744073709551613)]
+    fn is_not_divisible_by_73(#[case] n: u64) {
+        let result = divisible_by_73(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(73)]
+    #[case(2701)]
+    #[case(18446744073709551614)]
+    fn is_divisible_by_73(#[case] n: u64) {
+        let result = divisible_by_73(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_73(base: u32) {
+        let n = 73 * (base as u64);
+        for i in 1..73 {
+            assert!(!divisible_by_73(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_73(base: u32) {
+        let n = 73 * (base as u64);
+        let result = divisible_by_73(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(73)]
+    #[case(519)]
+    #[case(18446744073709551603)]
+    fn is_not_divisible_by_74(#[case] n: u64) {
+        let result = divisible_by_74(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(74)]
+    #[case(2738)]
+    #[case(18446744073709551604)]
+    fn is_divisible_by_74(#[case] n: u64) {
+        let result = divisible_by_74(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_74(base: u32) {
+        let n = 74 * (base as u64);
+        for i in 1..74 {
+            assert!(!divisible_by_74(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_74(base: u32) {
+        let n = 74 * (base as u64);
+        let result = divisible_by_74(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(74)]
+    #[case(526)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_75(#[case] n: u64) {
+        let result = divisible_by_75(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(75)]
+    #[case(2775)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_75(#[case] n: u64) {
+        let result = divisible_by_75(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_75(base: u32) {
+        let n = 75 * (base as u64);
+        for i in 1..75 {
+            assert!(!divisible_by_75(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_75(base: u32) {
+        let n = 75 * (base as u64);
+        let result = divisible_by_75(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(75)]
+    #[case(533)]
+    #[case(18446744073709551579)]
+    fn is_not_divisible_by_76(#[case] n: u64) {
+        let result = divisible_by_76(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(76)]
+    #[case(2812)]
+    #[case(18446744073709551580)]
+    fn is_divisible_by_76(#[case] n: u64) {
+        let result = divisible_by_76(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_76(base: u32) {
+        let n = 76 * (base as u64);
+        for i in 1..76 {
+            assert!(!divisible_by_76(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_76(base: u32) {
+        let n = 76 * (base as u64);
+        let result = divisible_by_76(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(76)]
+    #[case(540)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_77(#[case] n: u64) {
+        let result = divisible_by_77(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(77)]
+    #[case(2849)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_77(#[case] n: u64) {
+        let result = divisible_by_77(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_77(base: u32) {
+        let n = 77 * (base as u64);
+        for i in 1..77 {
+            assert!(!divisible_by_77(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_77(base: u32) {
+        let n = 77 * (base as u64);
+        let result = divisible_by_77(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(77)]
+    #[case(547)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_78(#[case] n: u64) {
+        let result = divisible_by_78(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(78)]
+    #[case(2886)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_78(#[case] n: u64) {
+        let result = divisible_by_78(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_78(base: u32) {
+        let n = 78 * (base as u64);
+        for i in 1..78 {
+            assert!(!divisible_by_78(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_78(base: u32) {
+        let n = 78 * (base as u64);
+        let result = divisible_by_78(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(78)]
+    #[case(554)]
+    #[case(18446744073709551564)]
+    fn is_not_divisible_by_79(#[case] n: u64) {
+        let result = divisible_by_79(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(79)]
+    #[case(2923)]
+    #[case(18446744073709551565)]
+    fn is_divisible_by_79(#[case] n: u64) {
+        let result = divisible_by_79(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_79(base: u32) {
+        let n = 79 * (base as u64);
+        for i in 1..79 {
+            assert!(!divisible_by_79(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_79(base: u32) {
+        let n = 79 * (base as u64);
+        let result = divisible_by_79(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(79)]
+    #[case(561)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_80(#[case] n: u64) {
+        let result = divisible_by_80(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(80)]
+    #[case(2960)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_80(#[case] n: u64) {
+        let result = divisible_by_80(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_80(base: u32) {
+        let n = 80 * (base as u64);
+        for i in 1..80 {
+            assert!(!divisible_by_80(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_80(base: u32) {
+        let n = 80 * (base as u64);
+        let result = divisible_by_80(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(80)]
+    #[case(568)]
+    #[case(18446744073709551563)]
+    fn is_not_divisible_by_81(#[case] n: u64) {
+        let result = divisible_by_81(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(81)]
+    #[case(2997)]
+    #[case(18446744073709551564)]
+    fn is_divisible_by_81(#[case] n: u64) {
+        let result = divisible_by_81(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_81(base: u32) {
+        let n = 81 * (base as u64);
+        for i in 1..81 {
+            assert!(!divisible_by_81(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_81(base: u32) {
+        let n = 81 * (base as u64);
+        let result = divisible_by_81(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(81)]
+    #[case(575)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_82(#[case] n: u64) {
+        let result = divisible_by_82(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(82)]
+    #[case(3034)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_82(#[case] n: u64) {
+        let result = divisible_by_82(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_82(base: u32) {
+        let n = 82 * (base as u64);
+        for i in 1..82 {
+            assert!(!divisible_by_82(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_82(base: u32) {
+        let n = 82 * (base as u64);
+        let result = divisible_by_82(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(82)]
+    #[case(582)]
+    #[case(18446744073709551579)]
+    fn is_not_divisible_by_83(#[case] n: u64) {
+        let result = divisible_by_83(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(83)]
+    #[case(3071)]
+    #[case(18446744073709551580)]
+    fn is_divisible_by_83(#[case] n: u64) {
+        let result = divisible_by_83(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_83(base: u32) {
+        let n = 83 * (base as u64);
+        for i in 1..83 {
+            assert!(!divisible_by_83(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_83(base: u32) {
+        let n = 83 * (base as u64);
+        let result = divisible_by_83(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(83)]
+    #[case(589)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_84(#[case] n: u64) {
+        let result = divisible_by_84(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(84)]
+    #[case(3108)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_84(#[case] n: u64) {
+        let result = divisible_by_84(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_84(base: u32) {
+        let n = 84 * (base as u64);
+        for i in 1..84 {
+            assert!(!divisible_by_84(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_84(base: u32) {
+        let n = 84 * (base as u64);
+        let result = divisible_by_84(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(84)]
+    #[case(596)]
+    #[case(18446744073709551614)]
+    fn is_not_divisible_by_85(#[case] n: u64) {
+        let result = divisible_by_85(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(85)]
+    #[case(3145)]
+    #[case(18446744073709551615)]
+    fn is_divisible_by_85(#[case] n: u64) {
+        let result = divisible_by_85(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_85(base: u32) {
+        let n = 85 * (base as u64);
+        for i in 1..85 {
+            assert!(!divisible_by_85(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_85(base: u32) {
+        let n = 85 * (base as u64);
+        let result = divisible_by_85(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(85)]
+    #[case(603)]
+    #[case(18446744073709551531)]
+    fn is_not_divisible_by_86(#[case] n: u64) {
+        let result = divisible_by_86(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(86)]
+    #[case(3182)]
+    #[case(18446744073709551532)]
+    fn is_divisible_by_86(#[case] n: u64) {
+        let result = divisible_by_86(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_86(base: u32) {
+        let n = 86 * (base as u64);
+        for i in 1..86 {
+            assert!(!divisible_by_86(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_86(base: u32) {
+        let n = 86 * (base as u64);
+        let result = divisible_by_86(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(86)]
+    #[case(610)]
+    #[case(18446744073709551533)]
+    fn is_not_divisible_by_87(#[case] n: u64) {
+        let result = divisible_by_87(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(87)]
+    #[case(3219)]
+    #[case(18446744073709551534)]
+    fn is_divisible_by_87(#[case] n: u64) {
+        let result = divisible_by_87(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_87(base: u32) {
+        let n = 87 * (base as u64);
+        for i in 1..87 {
+            assert!(!divisible_by_87(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_87(base: u32) {
+        let n = 87 * (base as u64);
+        let result = divisible_by_87(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(87)]
+    #[case(617)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_88(#[case] n: u64) {
+        let result = divisible_by_88(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(88)]
+    #[case(3256)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_88(#[case] n: u64) {
+        let result = divisible_by_88(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_88(base: u32) {
+        let n = 88 * (base as u64);
+        for i in 1..88 {
+            assert!(!divisible_by_88(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_88(base: u32) {
+        let n = 88 * (base as u64);
+        let result = divisible_by_88(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(88)]
+    #[case(624)]
+    #[case(18446744073709551548)]
+    fn is_not_divisible_by_89(#[case] n: u64) {
+        let result = divisible_by_89(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(89)]
+    #[case(3293)]
+    #[case(18446744073709551549)]
+    fn is_divisible_by_89(#[case] n: u64) {
+        let result = divisible_by_89(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_89(base: u32) {
+        let n = 89 * (base as u64);
+        for i in 1..89 {
+            assert!(!divisible_by_89(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_89(base: u32) {
+        let n = 89 * (base as u64);
+        let result = divisible_by_89(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(89)]
+    #[case(631)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_90(#[case] n: u64) {
+        let result = divisible_by_90(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(90)]
+    #[case(3330)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_90(#[case] n: u64) {
+        let result = divisible_by_90(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_90(base: u32) {
+        let n = 90 * (base as u64);
+        for i in 1..90 {
+            assert!(!divisible_by_90(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_90(base: u32) {
+        let n = 90 * (base as u64);
+        let result = divisible_by_90(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(90)]
+    #[case(638)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_91(#[case] n: u64) {
+        let result = divisible_by_91(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(91)]
+    #[case(3367)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_91(#[case] n: u64) {
+        let result = divisible_by_91(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_91(base: u32) {
+        let n = 91 * (base as u64);
+        for i in 1..91 {
+            assert!(!divisible_by_91(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_91(base: u32) {
+        let n = 91 * (base as u64);
+        let result = divisible_by_91(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(91)]
+    #[case(645)]
+    #[case(18446744073709551563)]
+    fn is_not_divisible_by_92(#[case] n: u64) {
+        let result = divisible_by_92(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(92)]
+    #[case(3404)]
+    #[case(18446744073709551564)]
+    fn is_divisible_by_92(#[case] n: u64) {
+        let result = divisible_by_92(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_92(base: u32) {
+        let n = 92 * (base as u64);
+        for i in 1..92 {
+            assert!(!divisible_by_92(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_92(base: u32) {
+        let n = 92 * (base as u64);
+        let result = divisible_by_92(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(92)]
+    #[case(652)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_93(#[case] n: u64) {
+        let result = divisible_by_93(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(93)]
+    #[case(3441)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_93(#[case] n: u64) {
+        let result = divisible_by_93(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_93(base: u32) {
+        let n = 93 * (base as u64);
+        for i in 1..93 {
+            assert!(!divisible_by_93(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_93(base: u32) {
+        let n = 93 * (base as u64);
+        let result = divisible_by_93(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(93)]
+    #[case(659)]
+    #[case(18446744073709551543)]
+    fn is_not_divisible_by_94(#[case] n: u64) {
+        let result = divisible_by_94(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(94)]
+    #[case(3478)]
+    #[case(18446744073709551544)]
+    fn is_divisible_by_94(#[case] n: u64) {
+        let result = divisible_by_94(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_94(base: u32) {
+        let n = 94 * (base as u64);
+        for i in 1..94 {
+            assert!(!divisible_by_94(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_94(base: u32) {
+        let n = 94 * (base as u64);
+        let result = divisible_by_94(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(94)]
+    #[case(666)]
+    #[case(18446744073709551579)]
+    fn is_not_divisible_by_95(#[case] n: u64) {
+        let result = divisible_by_95(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(95)]
+    #[case(3515)]
+    #[case(18446744073709551580)]
+    fn is_divisible_by_95(#[case] n: u64) {
+        let result = divisible_by_95(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_95(base: u32) {
+        let n = 95 * (base as u64);
+        for i in 1..95 {
+            assert!(!divisible_by_95(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_95(base: u32) {
+        let n = 95 * (base as u64);
+        let result = divisible_by_95(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(95)]
+    #[case(673)]
+    #[case(18446744073709551551)]
+    fn is_not_divisible_by_96(#[case] n: u64) {
+        let result = divisible_by_96(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(96)]
+    #[case(3552)]
+    #[case(18446744073709551552)]
+    fn is_divisible_by_96(#[case] n: u64) {
+        let result = divisible_by_96(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_96(base: u32) {
+        let n = 96 * (base as u64);
+        for i in 1..96 {
+            assert!(!divisible_by_96(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_96(base: u32) {
+        let n = 96 * (base as u64);
+        let result = divisible_by_96(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(96)]
+    #[case(680)]
+    #[case(18446744073709551554)]
+    fn is_not_divisible_by_97(#[case] n: u64) {
+        let result = divisible_by_97(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(97)]
+    #[case(3589)]
+    #[case(18446744073709551555)]
+    fn is_divisible_by_97(#[case] n: u64) {
+        let result = divisible_by_97(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_97(base: u32) {
+        let n = 97 * (base as u64);
+        for i in 1..97 {
+            assert!(!divisible_by_97(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_97(base: u32) {
+        let n = 97 * (base as u64);
+        let result = divisible_by_97(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(97)]
+    #[case(687)]
+    #[case(18446744073709551613)]
+    fn is_not_divisible_by_98(#[case] n: u64) {
+        let result = divisible_by_98(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(98)]
+    #[case(3626)]
+    #[case(18446744073709551614)]
+    fn is_divisible_by_98(#[case] n: u64) {
+        let result = divisible_by_98(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_98(base: u32) {
+        let n = 98 * (base as u64);
+        for i in 1..98 {
+            assert!(!divisible_by_98(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_98(base: u32) {
+        let n = 98 * (base as u64);
+        let result = divisible_by_98(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(98)]
+    #[case(694)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_99(#[case] n: u64) {
+        let result = divisible_by_99(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(99)]
+    #[case(3663)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_99(#[case] n: u64) {
+        let result = divisible_by_99(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_99(base: u32) {
+        let n = 99 * (base as u64);
+        for i in 1..99 {
+            assert!(!divisible_by_99(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_99(base: u32) {
+        let n = 99 * (base as u64);
+        let result = divisible_by_99(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(99)]
+    #[case(701)]
+    #[case(18446744073709551599)]
+    fn is_not_divisible_by_100(#[case] n: u64) {
+        let result = divisible_by_100(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(100)]
+    #[case(3700)]
+    #[case(18446744073709551600)]
+    fn is_divisible_by_100(#[case] n: u64) {
+        let result = divisible_by_100(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_100(base: u32) {
+        let n = 100 * (base as u64);
+        for i in 1..100 {
+            assert!(!divisible_by_100(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_100(base: u32) {
+        let n = 100 * (base as u64);
+        let result = divisible_by_100(n);
+        assert!(result);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_13() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_13(n), n % 13 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_14() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_14(n), n % 14 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_15() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_15(n), n % 15 == 0);
+    }
+
+    #[kani::proof]
+    pub fn check_divisible_by_16() {
+        let n: u8 = kani::any();
+        assert_eq!(divisible_by_16(n.into()), n % 16 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_17() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_17(n), n % 17 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_18() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_18(n), n % 18 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_19() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_19(n), n % 19 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_20() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_20(n), n % 20 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_21() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_21(n), n % 21 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_22() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_22(n), n % 22 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_23() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_23(n), n % 23 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_24() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_24(n), n % 24 == 0);
+    }
+
+    #[kani::proof]
+    pub fn check_divisible_by_25() {
+        let n: u16 = kani::any();
+        assert_eq!(divisible_by_25(n.into()), n % 25 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_26() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_26(n), n % 26 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_27() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_27(n), n % 27 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_28() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_28(n), n % 28 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_29() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_29(n), n % 29 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_30() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_30(n), n % 30 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_31() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_31(n), n % 31 == 0);
+    }
+
+    #[kani::proof]
+    pub fn check_divisible_by_32() {
+        let n: u8 = kani::any();
+        assert_eq!(divisible_by_32(n.into()), n % 32 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_33() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_33(n), n % 33 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_34() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_34(n), n % 34 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_35() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_35(n), n % 35 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_36() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_36(n), n % 36 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_37() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_37(n), n % 37 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_38() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_38(n), n % 38 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_39() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_39(n), n % 39 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_40() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_40(n), n % 40 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_41() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_41(n), n % 41 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_42() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_42(n), n % 42 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_43() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_43(n), n % 43 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_44() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_44(n), n % 44 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_45() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_45(n), n % 45 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_46() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_46(n), n % 46 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_47() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_47(n), n % 47 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_48() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_48(n), n % 48 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_49() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_49(n), n % 49 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_50() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_50(n), n % 50 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_51() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_51(n), n % 51 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_52() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_52(n), n % 52 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_53() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_53(n), n % 53 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_54() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_54(n), n % 54 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_55() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_55(n), n % 55 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_56() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_56(n), n % 56 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_57() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_57(n), n % 57 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_58() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_58(n), n % 58 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_59() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_59(n), n % 59 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_60() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_60(n), n % 60 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_61() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_61(n), n % 61 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_62() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_62(n), n % 62 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_63() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_63(n), n % 63 == 0);
+    }
+
+    #[kani::proof]
+    pub fn check_divisible_by_64() {
+        let n: u8 = kani::any();
+        assert_eq!(divisible_by_64(n.into()), n % 64 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_65() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_65(n), n % 65 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_66() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_66(n), n % 66 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_67() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_67(n), n % 67 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_68() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_68(n), n % 68 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_69() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_69(n), n % 69 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_70() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_70(n), n % 70 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_71() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_71(n), n % 71 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_72() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_72(n), n % 72 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_73() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_73(n), n % 73 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_74() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_74(n), n % 74 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_75() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_75(n), n % 75 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_76() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_76(n), n % 76 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_77() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_77(n), n % 77 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_78() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_78(n), n % 78 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_79() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_79(n), n % 79 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_80() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_80(n), n % 80 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_81() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_81(n), n % 81 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_82() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_82(n), n % 82 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_83() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_83(n), n % 83 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_84() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_84(n), n % 84 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_85() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_85(n), n % 85 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_86() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_86(n), n % 86 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_87() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_87(n), n % 87 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_88() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_88(n), n % 88 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_89() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_89(n), n % 89 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_90() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_90(n), n % 90 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_91() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_91(n), n % 91 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_92() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_92(n), n % 92 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_93() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_93(n), n % 93 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_94() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_94(n), n % 94 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_95() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_95(n), n % 95 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_96() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_96(n), n % 96 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_97() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_97(n), n % 97 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_98() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_98(n), n % 98 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_99() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_99(n), n % 99 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_100() {
+        let n: u64 = kani::any();
+        assert_eq!(divisible_by_100(n), n % 100 == 0);
+    }
+}
@@ -0,0 +1,257 @@
+//! Prime factorisation and divisor functions built on the digit rules.
+//!
+//! Trial division asks every candidate prime whether it divides what is left
+//! of the number. The primes below one hundred (100) answer with their own
+//! digit rule, e.g. [`divisible_by_7`], so a factorisation shows how the
+//! individual rules add up. Larger candidates are only reached for numbers
+//! with large prime factors and fall back to the remainder operator.
+
+use alloc::vec;
+use alloc::vec::Vec;
+
+use crate::is_prime;
+use crate::primality::SMALL_PRIMES;
+
+/// Returns the prime factors of the provided number with their exponents, in
+/// ascending order of the prime. Zero (0) and one (1) have no prime factors.
+///
+/// The primes below one hundred (100) are found with their digit rules, e.g.
+/// [`divisible_by_7`](crate::divisible_by_7), and larger primes with trial
+/// division until the rest of the number is prime according to [`is_prime`].
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{factorize};
+/// assert_eq!(factorize(360), [(2, 3), (3, 2), (5, 1)]);
+/// assert_eq!(factorize(1001), [(7, 1), (11, 1), (13, 1)]);
+/// assert_eq!(factorize(1009), [(1009, 1)]);
+/// assert_eq!(factorize(1), []);
+/// ```
+pub fn factorize(n: u64) -> Vec<(u64, u32)> {
+    let mut factors = Vec::new();
+    if n < 2 {
+        return factors;
+    }
+
+    let mut rest = n;
+    for (prime, rule) in SMALL_PRIMES {
+        let mut exponent = 0;
+        while rule(rest) {
+            rest /= prime;
+            exponent += 1;
+        }
+
+        if exponent > 0 {
+            factors.push((prime, exponent));
+        }
+    }
+
+    // Every candidate from here on is odd and at least 101, so it is only a
+    // prime factor if no smaller prime divided it. The search stops early once
+    // the rest is prime, which spares the trial divisions up to its root.
+    let mut candidate = 101;
+    let mut rest_is_prime = is_prime(rest);
+    while candidate <= rest / candidate && !rest_is_prime {
+        let mut exponent = 0;
+        while rest.is_multiple_of(candidate) {
+            rest /= candidate;
+            exponent += 1;
+        }
+
+        if exponent > 0 {
+            factors.push((candidate, exponent));
+            rest_is_prime = is_prime(rest);
+        }
+
+        candidate += 2;
+    }
+
+    if rest > 1 {
+        factors.push((rest, 1));
+    }
+
+    factors
+}
+
+/// Returns every divisor of the provided number in ascending order. Zero (0)
+/// is divisible by every number, so it returns no divisors.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisors};
+/// assert_eq!(divisors(12), [1, 2, 3, 4, 6, 12]);
+/// assert_eq!(divisors(49), [1, 7, 49]);
+/// assert_eq!(divisors(1), [1]);
+/// assert_eq!(divisors(0), []);
+/// ```
+pub fn divisors(n: u64) -> Vec<u64> {
+    if n == 0 {
+        return Vec::new();
+    }
+
+    let mut divisors = vec![1];
+    for (prime, exponent) in factorize(n) {
+        let previous = divisors.len();
+        let mut power = 1;
+        for _ in 0..exponent {
+            power *= prime;
+            for i in 0..previous {
+                divisors.push(divisors[i] * power);
+            }
+        }
+    }
+
+    divisors.sort_unstable();
+    divisors
+}
+
+/// Returns the number of divisors of the provided number, i.e. the divisor
+/// function τ(n). Zero (0) returns zero.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisor_count};
+/// assert_eq!(divisor_count(12), 6);
+/// assert_eq!(divisor_count(1009), 2);
+/// assert_eq!(divisor_count(1), 1);
+/// ```
+pub fn divisor_count(n: u64) -> u64 {
+    if n == 0 {
+        return 0;
+    }
+
+    factorize(n)
+        .iter()
+        .map(|&(_, exponent)| u64::from(exponent) + 1)
+        .product()
+}
+
+/// Returns the sum of the divisors of the provided number, i.e. the divisor
+/// function σ(n). Zero (0) returns zero.
+///
+/// The sum is a `u128` since it exceeds the number itself, e.g. σ(n) is more
+/// than twice n for every abundant number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisor_sum};
+/// assert_eq!(divisor_sum(12), 28);
+/// assert_eq!(divisor_sum(28), 56);
+/// assert_eq!(divisor_sum(1), 1);
+/// ```
+pub fn divisor_sum(n: u64) -> u128 {
+    if n == 0 {
+        return 0;
+    }
+
+    factorize(n)
+        .iter()
+        .map(|&(prime, exponent)| {
+            let prime = u128::from(prime);
+            (0..exponent).fold(1, |sum, _| sum * prime + 1)
+        })
+        .product()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, vec![])]
+    #[case(1, vec![])]
+    #[case(2, vec![(2, 1)])]
+    #[case(360, vec![(2, 3), (3, 2), (5, 1)])]
+    #[case(1001, vec![(7, 1), (11, 1), (13, 1)])]
+    #[case(97 * 97 * 101, vec![(97, 2), (101, 1)])]
+    #[case(1 << 63, vec![(2, 63)])]
+    #[case(u64::MAX, vec![(3, 1), (5, 1), (17, 1), (257, 1), (641, 1), (65537, 1), (6700417, 1)])]
+    #[case(1000003 * 1000033, vec![(1000003, 1), (1000033, 1)])]
+    fn is_the_factorisation(#[case] n: u64, #[case] expected: Vec<(u64, u32)>) {
+        assert_eq!(factorize(n), expected);
+    }
+
+    #[proptest]
+    fn is_a_factorisation(n: u32) {
+        let n = u64::from(n);
+        let factors = factorize(n);
+        let product: u64 = factors
+            .iter()
+            .map(|&(prime, exponent)| prime.pow(exponent))
+            .product();
+        assert_eq!(product, n.max(1));
+
+        for window in factors.windows(2) {
+            assert!(window[0].0 < window[1].0);
+        }
+
+        for &(prime, _) in &factors {
+            assert!((2..prime)
+                .take_while(|d| d * d <= prime)
+                .all(|d| prime % d != 0));
+        }
+    }
+
+    #[rstest]
+    #[case(0, vec![])]
+    #[case(1, vec![1])]
+    #[case(12, vec![1, 2, 3, 4, 6, 12])]
+    #[case(49, vec![1, 7, 49])]
+    #[case(1009, vec![1, 1009])]
+    fn are_the_divisors(#[case] n: u64, #[case] expected: Vec<u64>) {
+        assert_eq!(divisors(n), expected);
+    }
+
+    #[proptest]
+    fn are_the_divisors_like_filtering(n: u16) {
+        let n = u64::from(n);
+        let expected: Vec<u64> = (1..=n).filter(|d| n % d == 0).collect();
+        assert_eq!(divisors(n), expected);
+    }
+
+    #[rstest]
+    #[case(0, 0, 0)]
+    #[case(1, 1, 1)]
+    #[case(12, 6, 28)]
+    #[case(28, 6, 56)]
+    #[case(1009, 2, 1010)]
+    #[case(1 << 63, 64, u128::from(u64::MAX))]
+    fn are_the_divisor_functions(#[case] n: u64, #[case] count: u64, #[case] sum: u128) {
+        assert_eq!(divisor_count(n), count);
+        assert_eq!(divisor_sum(n), sum);
+    }
+
+    #[proptest]
+    fn are_the_divisor_functions_of_the_divisors(n: u32) {
+        let n = u64::from(n);
+        let divisors = divisors(n);
+        assert_eq!(divisor_count(n), divisors.len() as u64);
+        assert_eq!(
+            divisor_sum(n),
+            divisors.iter().map(|&d| u128::from(d)).sum()
+        );
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(27)]
+    pub fn check_factorize() {
+        let n: u8 = kani::any();
+        let product: u64 = factorize(n.into())
+            .iter()
+            .map(|&(prime, exponent)| prime.pow(exponent))
+            .product();
+        assert!(product == u64::from(n).max(1));
+    }
+}
@@ -0,0 +1,179 @@
+//! Divisibility checks that report a zero (0) divisor instead of hiding it.
+//!
+//! The infallible [`divisible_by_0`] returns `false` for every number, which
+//! makes a divisor that is zero by mistake look like a divisor that never
+//! divides anything. [`try_divisible_by`] returns a [`DivisibilityError`] for
+//! it instead, and a [`ZeroPolicy`] chooses between that error, the
+//! mathematical convention that zero divides only zero, and the legacy `false`.
+//!
+//! [`divisible_by_0`]: crate::divisible_by_0
+
+use core::fmt;
+
+use crate::divisible_by;
+
+/// The reason a divisibility check could not be answered.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum DivisibilityError {
+    /// The divisor is zero (0).
+    DivisionByZero {
+        /// The number that was checked.
+        n: u64,
+    },
+}
+
+impl fmt::Display for DivisibilityError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DivisibilityError::DivisionByZero { n } => {
+                write!(f, "cannot check if {n} is divisible by zero")
+            }
+        }
+    }
+}
+
+impl core::error::Error for DivisibilityError {}
+
+/// The meaning of divisibility by zero (0).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{DivisibilityError, ZeroPolicy};
+/// let error = DivisibilityError::DivisionByZero { n: 0 };
+/// assert_eq!(ZeroPolicy::Strict.divisible_by(0, 0), Err(error));
+/// assert_eq!(ZeroPolicy::Mathematical.divisible_by(0, 0), Ok(true));
+/// assert_eq!(ZeroPolicy::Mathematical.divisible_by(42, 0), Ok(false));
+/// assert_eq!(ZeroPolicy::Legacy.divisible_by(0, 0), Ok(false));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub enum ZeroPolicy {
+    /// A zero divisor is an error, see [`DivisibilityError::DivisionByZero`].
+    #[default]
+    Strict,
+
+    /// A number is divisible by zero exactly when it is zero, i.e. when it is
+    /// zero times some number.
+    Mathematical,
+
+    /// No number is divisible by zero, like [`divisible_by_0`].
+    ///
+    /// [`divisible_by_0`]: crate::divisible_by_0
+    Legacy,
+}
+
+impl ZeroPolicy {
+    /// Determines if the provided number is divisible by the provided divisor,
+    /// treating a zero (0) divisor according to this policy.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`DivisibilityError::DivisionByZero`] if the divisor is zero
+    /// and the policy is [`ZeroPolicy::Strict`].
+    pub fn divisible_by(self, n: u64, d: u64) -> Result<bool, DivisibilityError> {
+        match (d, self) {
+            (0, ZeroPolicy::Strict) => Err(DivisibilityError::DivisionByZero { n }),
+            (0, ZeroPolicy::Mathematical) => Ok(n == 0),
+            (0, ZeroPolicy::Legacy) => Ok(false),
+            _ => Ok(divisible_by(n, d)),
+        }
+    }
+}
+
+/// Determines if the provided number is divisible by the provided divisor, or
+/// returns an error if the divisor is zero (0).
+///
+/// Use [`ZeroPolicy::divisible_by`] to give a zero divisor another meaning.
+///
+/// # Errors
+///
+/// Returns [`DivisibilityError::DivisionByZero`] if the divisor is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{try_divisible_by, DivisibilityError};
+/// assert_eq!(try_divisible_by(42, 7), Ok(true));
+/// assert_eq!(try_divisible_by(43, 7), Ok(false));
+/// assert_eq!(
+///     try_divisible_by(42, 0),
+///     Err(DivisibilityError::DivisionByZero { n: 42 })
+/// );
+/// ```
+pub fn try_divisible_by(n: u64, d: u64) -> Result<bool, DivisibilityError> {
+    ZeroPolicy::Strict.divisible_by(n, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0)]
+    #[case(42)]
+    #[case(u64::MAX)]
+    fn is_an_error_for_zero(#[case] n: u64) {
+        let result = try_divisible_by(n, 0);
+        assert_eq!(result, Err(DivisibilityError::DivisionByZero { n }));
+    }
+
+    #[proptest]
+    fn is_divisible_by_like_the_rules(n: u64, d: u64) {
+        let d = d.max(1);
+        assert_eq!(try_divisible_by(n, d), Ok(divisible_by(n, d)));
+    }
+
+    #[rstest]
+    #[case(ZeroPolicy::Strict, 0, Err(DivisibilityError::DivisionByZero { n: 0 }))]
+    #[case(ZeroPolicy::Strict, 42, Err(DivisibilityError::DivisionByZero { n: 42 }))]
+    #[case(ZeroPolicy::Mathematical, 0, Ok(true))]
+    #[case(ZeroPolicy::Mathematical, 42, Ok(false))]
+    #[case(ZeroPolicy::Legacy, 0, Ok(false))]
+    #[case(ZeroPolicy::Legacy, 42, Ok(false))]
+    fn is_divisible_by_zero_with_policy(
+        #[case] policy: ZeroPolicy,
+        #[case] n: u64,
+        #[case] expected: Result<bool, DivisibilityError>,
+    ) {
+        assert_eq!(policy.divisible_by(n, 0), expected);
+    }
+
+    #[proptest]
+    fn are_policies_alike_for_non_zero_divisors(n: u64, d: u64) {
+        let d = d.max(1);
+        for policy in [
+            ZeroPolicy::Strict,
+            ZeroPolicy::Mathematical,
+            ZeroPolicy::Legacy,
+        ] {
+            assert_eq!(policy.divisible_by(n, d), Ok(divisible_by(n, d)));
+        }
+    }
+
+    #[test]
+    fn is_strict_by_default() {
+        assert_eq!(ZeroPolicy::default(), ZeroPolicy::Strict);
+    }
+
+    #[test]
+    fn is_displayed() {
+        let error = DivisibilityError::DivisionByZero { n: 42 };
+        assert_eq!(error.to_string(), "cannot check if 42 is divisible by zero");
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    pub fn check_zero_policy() {
+        let n: u64 = kani::any();
+        assert!(ZeroPolicy::Strict.divisible_by(n, 0).is_err());
+        assert!(ZeroPolicy::Mathematical.divisible_by(n, 0) == Ok(n == 0));
+        assert!(ZeroPolicy::Legacy.divisible_by(n, 0) == Ok(crate::divisible_by_0(n)));
+    }
+}
@@ -0,0 +1,568 @@
+//! Common divisors, greatest common divisors and least common multiples.
+//!
+//! A prime divides the greatest common divisor of some numbers exactly when
+//! its rule holds for every one of them. The primes below one hundred (100)
+//! are divided out this way, e.g. with [`divisible_by_7`], and only what is
+//! left is handed to Euclid's algorithm. Numbers written in decimal use the
+//! binary GCD algorithm instead, which only needs the rule for two (2) to
+//! halve the numbers and subtraction to shrink them.
+//!
+//! [`divisible_by_7`]: crate::divisible_by_7
+
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+
+#[cfg(feature = "alloc")]
+use crate::decimal::digits;
+use crate::primality::SMALL_PRIMES;
+#[cfg(feature = "alloc")]
+use crate::{
+    divisible_by_10_str, divisible_by_11_str, divisible_by_12_str, divisible_by_1_str,
+    divisible_by_2, divisible_by_2_str, divisible_by_3_str, divisible_by_4_str, divisible_by_5_str,
+    divisible_by_6_str, divisible_by_7_str, divisible_by_8_str, divisible_by_9_str, ParseError,
+};
+use crate::{divisors_up_to_12, DivisorSet};
+
+/// A rule for a decimal string with its divisor.
+#[cfg(feature = "alloc")]
+type StrRule = (u64, fn(&str) -> Result<bool, ParseError>);
+
+/// The rules for decimal strings for the divisors one (1) through twelve (12).
+#[cfg(feature = "alloc")]
+const STR_RULES: [StrRule; 12] = [
+    (1, divisible_by_1_str),
+    (2, divisible_by_2_str),
+    (3, divisible_by_3_str),
+    (4, divisible_by_4_str),
+    (5, divisible_by_5_str),
+    (6, divisible_by_6_str),
+    (7, divisible_by_7_str),
+    (8, divisible_by_8_str),
+    (9, divisible_by_9_str),
+    (10, divisible_by_10_str),
+    (11, divisible_by_11_str),
+    (12, divisible_by_12_str),
+];
+
+/// Returns the set of numbers from zero (0) through twelve (12) that divide
+/// every one of the provided numbers, i.e. the divisors in that range of their
+/// greatest common divisor.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{common_divisors};
+/// let divisors = common_divisors(&[84, 126, 210]);
+/// assert_eq!(divisors.to_string(), "{1, 2, 3, 6, 7}");
+/// ```
+pub fn common_divisors(ns: &[u64]) -> DivisorSet {
+    ns.iter().fold(divisors_up_to_12(0), |common, &n| {
+        common & divisors_up_to_12(n)
+    })
+}
+
+/// Returns the greatest common divisor of the provided numbers. The greatest
+/// common divisor of no numbers, or only zeros (0), is zero.
+///
+/// The primes below one hundred (100) that divide every number are found with
+/// their rules and divided out before Euclid's algorithm handles the rest.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{gcd_via_rules};
+/// assert_eq!(gcd_via_rules(&[84, 126, 210]), 42);
+/// assert_eq!(gcd_via_rules(&[1009 * 7, 1009 * 11]), 1009);
+/// assert_eq!(gcd_via_rules(&[0, 12]), 12);
+/// ```
+pub fn gcd_via_rules(ns: &[u64]) -> u64 {
+    ns.iter().fold(0, |gcd, &n| gcd_of_two(gcd, n))
+}
+
+/// Returns the least common multiple of the provided numbers, or `None` if it
+/// does not fit in a `u64`. The least common multiple of no numbers is one
+/// (1), and of any numbers including zero (0) it is zero.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{lcm_via_rules};
+/// assert_eq!(lcm_via_rules(&[4, 6, 10]), Some(60));
+/// assert_eq!(lcm_via_rules(&[0, 12]), Some(0));
+/// assert_eq!(lcm_via_rules(&[u64::MAX, 2]), None);
+/// ```
+pub fn lcm_via_rules(ns: &[u64]) -> Option<u64> {
+    if ns.contains(&0) {
+        return Some(0);
+    }
+
+    ns.iter()
+        .try_fold(1, |lcm: u64, &n| (lcm / gcd_of_two(lcm, n)).checked_mul(n))
+}
+
+/// Determines if the provided numbers are coprime, i.e. if their greatest
+/// common divisor is one (1).
+///
+/// The check stops at the first rule for a prime below one hundred (100) that
+/// holds for every number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{coprime_via_rules};
+/// assert!(coprime_via_rules(&[14, 15]));
+/// assert!(!coprime_via_rules(&[14, 21]));
+/// assert!(!coprime_via_rules(&[1009 * 2, 1009 * 3]));
+/// ```
+pub fn coprime_via_rules(ns: &[u64]) -> bool {
+    let shares_small_prime = SMALL_PRIMES
+        .iter()
+        .any(|&(_, rule)| ns.iter().all(|&n| rule(n)));
+
+    !shares_small_prime && gcd_via_rules(ns) == 1
+}
+
+/// Returns the set of numbers from zero (0) through twelve (12) that divide
+/// every one of the numbers in the provided decimal strings, see
+/// [`common_divisors`].
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if any string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{common_divisors_str};
+/// let divisors = common_divisors_str(&["84", "126000000000000000000000000"]).unwrap();
+/// assert_eq!(divisors.to_string(), "{1, 2, 3, 4, 6, 7, 12}");
+/// ```
+#[cfg(feature = "alloc")]
+pub fn common_divisors_str(ns: &[&str]) -> Result<DivisorSet, ParseError> {
+    ns.iter().try_fold(divisors_up_to_12(0), |common, n| {
+        let mut divisors = DivisorSet::new();
+        for (d, rule) in STR_RULES {
+            if rule(n)? {
+                divisors.insert(d);
+            }
+        }
+
+        Ok(common & divisors)
+    })
+}
+
+/// Returns the greatest common divisor of the numbers in the provided decimal
+/// strings, written in decimal, see [`gcd_via_rules`].
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if any string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{gcd_via_rules_str};
+/// let gcd = gcd_via_rules_str(&["123456789012345678901234567890", "9876543210"]);
+/// assert_eq!(gcd.as_deref(), Ok("90"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn gcd_via_rules_str(ns: &[&str]) -> Result<String, ParseError> {
+    let gcd = ns
+        .iter()
+        .try_fold(Vec::new(), |gcd, n| Ok(binary_gcd(gcd, parse(n)?)))?;
+
+    Ok(format(&gcd))
+}
+
+/// Returns the least common multiple of the numbers in the provided decimal
+/// strings, written in decimal, see [`lcm_via_rules`]. The result is never too
+/// large, since it is a string as well.
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if any string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{lcm_via_rules_str};
+/// let lcm = lcm_via_rules_str(&["18446744073709551615", "2"]);
+/// assert_eq!(lcm.as_deref(), Ok("36893488147419103230"));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn lcm_via_rules_str(ns: &[&str]) -> Result<String, ParseError> {
+    let numbers = ns.iter().map(|n| parse(n)).collect::<Result<Vec<_>, _>>()?;
+    if numbers.iter().any(Vec::is_empty) {
+        return Ok(format(&[]));
+    }
+
+    let lcm = numbers.into_iter().fold(vec![1], |lcm, n| {
+        let gcd = binary_gcd(lcm.clone(), n.clone());
+        multiply(&divide(&lcm, &gcd), &n)
+    });
+
+    Ok(format(&lcm))
+}
+
+/// Determines if the numbers in the provided decimal strings are coprime, see
+/// [`coprime_via_rules`].
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] if any string is not a plain decimal number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{coprime_via_rules_str};
+/// assert_eq!(coprime_via_rules_str(&["100000000000000000000001", "10"]), Ok(true));
+/// assert_eq!(coprime_via_rules_str(&["100000000000000000000002", "10"]), Ok(false));
+/// ```
+#[cfg(feature = "alloc")]
+pub fn coprime_via_rules_str(ns: &[&str]) -> Result<bool, ParseError> {
+    Ok(gcd_via_rules_str(ns)? == "1")
+}
+
+fn gcd_of_two(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        return a | b;
+    }
+
+    let (mut a, mut b, mut common) = (a, b, 1);
+    for (prime, rule) in SMALL_PRIMES {
+        while rule(a) && rule(b) {
+            common *= prime;
+            a /= prime;
+            b /= prime;
+        }
+    }
+
+    common * euclid(a, b)
+}
+
+fn euclid(a: u64, b: u64) -> u64 {
+    match b {
+        0 => a,
+        _ => euclid(b, a % b),
+    }
+}
+
+/// Returns the digits of the number in the provided decimal string, most
+/// significant first and without leading zeros, so zero (0) has no digits.
+#[cfg(feature = "alloc")]
+fn parse(n: &str) -> Result<Vec<u8>, ParseError> {
+    let digits = digits(n)?;
+    let leading_zeros = digits.iter().take_while(|&&digit| digit == b'0').count();
+    Ok(digits[leading_zeros..]
+        .iter()
+        .map(|digit| digit - b'0')
+        .collect())
+}
+
+#[cfg(feature = "alloc")]
+fn format(digits: &[u8]) -> String {
+    if digits.is_empty() {
+        return String::from("0");
+    }
+
+    digits
+        .iter()
+        .map(|&digit| char::from(b'0' + digit))
+        .collect()
+}
+
+#[cfg(feature = "alloc")]
+fn trim(digits: &mut Vec<u8>) {
+    let leading_zeros = digits.iter().take_while(|&&digit| digit == 0).count();
+    digits.drain(..leading_zeros);
+}
+
+#[cfg(feature = "alloc")]
+fn is_even(digits: &[u8]) -> bool {
+    digits
+        .last()
+        .is_none_or(|&digit| divisible_by_2(digit.into()))
+}
+
+#[cfg(feature = "alloc")]
+fn compare(a: &[u8], b: &[u8]) -> Ordering {
+    a.len().cmp(&b.len()).then_with(|| a.cmp(b))
+}
+
+/// Computes the greatest common divisor with the binary GCD algorithm, which
+/// halves even numbers and subtracts the smaller odd number from the larger.
+#[cfg(feature = "alloc")]
+fn binary_gcd(mut a: Vec<u8>, mut b: Vec<u8>) -> Vec<u8> {
+    if a.is_empty() {
+        return b;
+    }
+    if b.is_empty() {
+        return a;
+    }
+
+    let mut shift = 0;
+    while is_even(&a) && is_even(&b) {
+        halve(&mut a);
+        halve(&mut b);
+        shift += 1;
+    }
+
+    while is_even(&a) {
+        halve(&mut a);
+    }
+
+    loop {
+        while is_even(&b) {
+            halve(&mut b);
+        }
+
+        if compare(&a, &b) == Ordering::Greater {
+            core::mem::swap(&mut a, &mut b);
+        }
+
+        subtract(&mut b, &a);
+        if b.is_empty() {
+            break;
+        }
+    }
+
+    (0..shift).fold(a, |a, _| multiply(&a, &[2]))
+}
+
+#[cfg(feature = "alloc")]
+fn halve(digits: &mut Vec<u8>) {
+    let mut carry = 0;
+    for digit in digits.iter_mut() {
+        let value = carry * 10 + *digit;
+        *digit = value / 2;
+        carry = value % 2;
+    }
+
+    trim(digits);
+}
+
+/// Subtracts the second number from the first, which must be at least as large.
+#[cfg(feature = "alloc")]
+fn subtract(a: &mut Vec<u8>, b: &[u8]) {
+    let mut borrow = 0;
+    for i in 0..a.len() {
+        let position = a.len() - 1 - i;
+        let subtrahend = b.len().checked_sub(i + 1).map_or(0, |j| b[j]) + borrow;
+        borrow = u8::from(a[position] < subtrahend);
+        a[position] = a[position] + 10 * borrow - subtrahend;
+    }
+
+    trim(a);
+}
+
+#[cfg(feature = "alloc")]
+fn multiply(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut product = vec![0u32; a.len() + b.len()];
+    for (i, &x) in a.iter().enumerate() {
+        for (j, &y) in b.iter().enumerate() {
+            product[i + j + 1] += u32::from(x) * u32::from(y);
+        }
+    }
+
+    for i in (1..product.len()).rev() {
+        product[i - 1] += product[i] / 10;
+        product[i] %= 10;
+    }
+
+    let mut product = product.into_iter().map(|digit| digit as u8).collect();
+    trim(&mut product);
+    product
+}
+
+/// Divides the first number by the second with long division, returning the
+/// quotient. Each digit of the quotient counts how often the divisor can be
+/// subtracted from the remainder so far.
+#[cfg(feature = "alloc")]
+fn divide(a: &[u8], b: &[u8]) -> Vec<u8> {
+    let mut quotient = Vec::with_capacity(a.len());
+    let mut remainder = Vec::with_capacity(b.len() + 1);
+    for &digit in a {
+        remainder.push(digit);
+        trim(&mut remainder);
+
+        let mut count = 0;
+        while compare(&remainder, b) != Ordering::Less {
+            subtract(&mut remainder, b);
+            count += 1;
+        }
+        quotient.push(count);
+    }
+
+    trim(&mut quotient);
+    quotient
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use num::BigUint;
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(&[], "{1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12}")]
+    #[case(&[0], "{1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12}")]
+    #[case(&[84, 126, 210], "{1, 2, 3, 6, 7}")]
+    #[case(&[360, 120], "{1, 2, 3, 4, 5, 6, 8, 10, 12}")]
+    #[case(&[14, 15], "{1}")]
+    fn are_the_common_divisors(#[case] ns: &[u64], #[case] expected: &str) {
+        assert_eq!(common_divisors(ns).to_string(), expected);
+    }
+
+    #[proptest]
+    fn are_the_common_divisors_of_the_gcd(a: u64, b: u64, c: u64) {
+        let ns = [a, b, c];
+        assert_eq!(common_divisors(&ns), divisors_up_to_12(gcd_via_rules(&ns)));
+    }
+
+    #[rstest]
+    #[case(&[], 0)]
+    #[case(&[0, 0], 0)]
+    #[case(&[0, 12], 12)]
+    #[case(&[42], 42)]
+    #[case(&[84, 126, 210], 42)]
+    #[case(&[1009 * 7, 1009 * 11], 1009)]
+    #[case(&[1 << 63, 3 << 62], 1 << 62)]
+    #[case(&[u64::MAX, u64::MAX / 3], u64::MAX / 3)]
+    fn is_the_gcd(#[case] ns: &[u64], #[case] expected: u64) {
+        assert_eq!(gcd_via_rules(ns), expected);
+    }
+
+    #[proptest]
+    fn is_the_gcd_like_num(a: u64, b: u64, c: u16) {
+        let c = a / u64::from(c.max(1));
+        assert_eq!(gcd_via_rules(&[a, b]), num::integer::gcd(a, b));
+        assert_eq!(
+            gcd_via_rules(&[a, b, c]),
+            num::integer::gcd(num::integer::gcd(a, b), c)
+        );
+    }
+
+    #[rstest]
+    #[case(&[], Some(1))]
+    #[case(&[0, 12], Some(0))]
+    #[case(&[4, 6, 10], Some(60))]
+    #[case(&[1 << 32, 1 << 31], Some(1 << 32))]
+    #[case(&[u64::MAX, 3], Some(u64::MAX))]
+    #[case(&[u64::MAX, 2], None)]
+    fn is_the_lcm(#[case] ns: &[u64], #[case] expected: Option<u64>) {
+        assert_eq!(lcm_via_rules(ns), expected);
+    }
+
+    #[proptest]
+    fn is_the_lcm_like_num(a: u32, b: u32) {
+        let (a, b) = (u64::from(a), u64::from(b));
+        assert_eq!(lcm_via_rules(&[a, b]), Some(num::integer::lcm(a, b)));
+    }
+
+    #[rstest]
+    #[case(&[], false)]
+    #[case(&[0, 1], true)]
+    #[case(&[0, 0], false)]
+    #[case(&[14, 15], true)]
+    #[case(&[14, 21], false)]
+    #[case(&[6, 10, 15], true)]
+    #[case(&[1009 * 2, 1009 * 3], false)]
+    fn are_coprime(#[case] ns: &[u64], #[case] expected: bool) {
+        assert_eq!(coprime_via_rules(ns), expected);
+    }
+
+    #[proptest]
+    fn are_coprime_like_num(a: u64, b: u64) {
+        assert_eq!(coprime_via_rules(&[a, b]), num::integer::gcd(a, b) == 1);
+    }
+
+    #[rstest]
+    #[case(&[], "{1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12}")]
+    #[case(&["84", "126", "210"], "{1, 2, 3, 6, 7}")]
+    #[case(&["0084", "126000000000000000000000000"], "{1, 2, 3, 4, 6, 7, 12}")]
+    fn are_the_common_divisors_str(#[case] ns: &[&str], #[case] expected: &str) {
+        assert_eq!(common_divisors_str(ns).unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    #[case(&[], "0")]
+    #[case(&["0", "000"], "0")]
+    #[case(&["0", "12"], "12")]
+    #[case(&["007"], "7")]
+    #[case(&["84", "126", "210"], "42")]
+    #[case(&["123456789012345678901234567890", "9876543210"], "90")]
+    #[case(&["340282366920938463463374607431768211456", "36893488147419103232"], "36893488147419103232")]
+    fn is_the_gcd_str(#[case] ns: &[&str], #[case] expected: &str) {
+        assert_eq!(gcd_via_rules_str(ns).as_deref(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(&[], "1")]
+    #[case(&["0", "12"], "0")]
+    #[case(&["4", "6", "10"], "60")]
+    #[case(&["18446744073709551615", "2"], "36893488147419103230")]
+    fn is_the_lcm_str(#[case] ns: &[&str], #[case] expected: &str) {
+        assert_eq!(lcm_via_rules_str(ns).as_deref(), Ok(expected));
+    }
+
+    #[rstest]
+    #[case(&["", "12"], ParseError::Empty)]
+    #[case(&["12", "-4"], ParseError::Sign)]
+    #[case(&["1.5"], ParseError::InvalidCharacter { character: '.', position: 1 })]
+    fn is_not_a_decimal_number(#[case] ns: &[&str], #[case] expected: ParseError) {
+        assert_eq!(common_divisors_str(ns), Err(expected));
+        assert_eq!(gcd_via_rules_str(ns), Err(expected));
+        assert_eq!(lcm_via_rules_str(ns), Err(expected));
+        assert_eq!(coprime_via_rules_str(ns), Err(expected));
+    }
+
+    #[proptest]
+    fn agree_with_the_integer_functions(a: u64, b: u64) {
+        let ns = [a.to_string(), b.to_string()];
+        let ns = [ns[0].as_str(), ns[1].as_str()];
+        assert_eq!(common_divisors_str(&ns), Ok(common_divisors(&[a, b])));
+        assert_eq!(
+            gcd_via_rules_str(&ns),
+            Ok(gcd_via_rules(&[a, b]).to_string())
+        );
+        assert_eq!(coprime_via_rules_str(&ns), Ok(coprime_via_rules(&[a, b])));
+    }
+
+    #[proptest]
+    fn are_the_gcd_and_lcm_like_num_beyond_u128(a: u128, b: u128, c: u64) {
+        let a = BigUint::from(a) * BigUint::from(c);
+        let b = BigUint::from(b) * BigUint::from(c);
+        let ns = [a.to_string(), b.to_string()];
+        let ns = [ns[0].as_str(), ns[1].as_str()];
+
+        let gcd = num::integer::gcd(a.clone(), b.clone());
+        assert_eq!(gcd_via_rules_str(&ns), Ok(gcd.to_string()));
+
+        let lcm = num::integer::lcm(a, b);
+        assert_eq!(lcm_via_rules_str(&ns), Ok(lcm.to_string()));
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(27)]
+    pub fn check_gcd_via_rules() {
+        let a: u8 = kani::any();
+        let b: u8 = kani::any();
+        let gcd = gcd_via_rules(&[a.into(), b.into()]);
+        if a != 0 || b != 0 {
+            assert!(gcd > 0 && u64::from(a) % gcd == 0 && u64::from(b) % gcd == 0);
+        }
+    }
+}
@@ -0,0 +1,387 @@
+use crate::utils::{alternating_digit_sum, digit_sum, last_digit};
+use crate::{
+    divisible_by_0, divisible_by_1, divisible_by_10, divisible_by_11, divisible_by_12,
+    divisible_by_2, divisible_by_3, divisible_by_4, divisible_by_5, divisible_by_6, divisible_by_7,
+    divisible_by_8, divisible_by_9,
+};
+
+/// Divisibility checks for the numbers 0 through 12 on any primitive integer.
+///
+/// Every width uses the same digit algorithms as the free functions, e.g.
+/// [`divisible_by_7`]. Signed integers are checked on their magnitude, so -21
+/// is divisible by seven (7) just like 21 is.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{DivisibleBy};
+/// assert!(42u8.divisible_by_7());
+/// assert!((-21i32).divisible_by_3());
+/// assert!(!u128::MAX.divisible_by_2());
+/// ```
+pub trait DivisibleBy {
+    /// Determines if the number is divisible by zero (0).
+    fn divisible_by_0(self) -> bool;
+
+    /// Determines if the number is divisible by one (1).
+    fn divisible_by_1(self) -> bool;
+
+    /// Determines if the number is divisible by two (2).
+    fn divisible_by_2(self) -> bool;
+
+    /// Determines if the number is divisible by three (3).
+    fn divisible_by_3(self) -> bool;
+
+    /// Determines if the number is divisible by four (4).
+    fn divisible_by_4(self) -> bool;
+
+    /// Determines if the number is divisible by five (5).
+    fn divisible_by_5(self) -> bool;
+
+    /// Determines if the number is divisible by six (6).
+    fn divisible_by_6(self) -> bool;
+
+    /// Determines if the number is divisible by seven (7).
+    fn divisible_by_7(self) -> bool;
+
+    /// Determines if the number is divisible by eight (8).
+    fn divisible_by_8(self) -> bool;
+
+    /// Determines if the number is divisible by nine (9).
+    fn divisible_by_9(self) -> bool;
+
+    /// Determines if the number is divisible by ten (10).
+    fn divisible_by_10(self) -> bool;
+
+    /// Determines if the number is divisible by eleven (11).
+    fn divisible_by_11(self) -> bool;
+
+    /// Determines if the number is divisible by twelve (12).
+    fn divisible_by_12(self) -> bool;
+}
+
+macro_rules! impl_divisible_by_unsigned {
+    ($($t:ty),*) => {
+        $(
+            impl DivisibleBy for $t {
+                fn divisible_by_0(self) -> bool {
+                    divisible_by_0(self as u64)
+                }
+
+                fn divisible_by_1(self) -> bool {
+                    divisible_by_1(self as u64)
+                }
+
+                fn divisible_by_2(self) -> bool {
+                    divisible_by_2(self as u64)
+                }
+
+                fn divisible_by_3(self) -> bool {
+                    divisible_by_3(self as u64)
+                }
+
+                fn divisible_by_4(self) -> bool {
+                    divisible_by_4(self as u64)
+                }
+
+                fn divisible_by_5(self) -> bool {
+                    divisible_by_5(self as u64)
+                }
+
+                fn divisible_by_6(self) -> bool {
+                    divisible_by_6(self as u64)
+                }
+
+                fn divisible_by_7(self) -> bool {
+                    divisible_by_7(self as u64)
+                }
+
+                fn divisible_by_8(self) -> bool {
+                    divisible_by_8(self as u64)
+                }
+
+                fn divisible_by_9(self) -> bool {
+                    divisible_by_9(self as u64)
+                }
+
+                fn divisible_by_10(self) -> bool {
+                    divisible_by_10(self as u64)
+                }
+
+                fn divisible_by_11(self) -> bool {
+                    divisible_by_11(self as u64)
+                }
+
+                fn divisible_by_12(self) -> bool {
+                    divisible_by_12(self as u64)
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_divisible_by_signed {
+    ($($t:ty),*) => {
+        $(
+            impl DivisibleBy for $t {
+                fn divisible_by_0(self) -> bool {
+                    self.unsigned_abs().divisible_by_0()
+                }
+
+                fn divisible_by_1(self) -> bool {
+                    self.unsigned_abs().divisible_by_1()
+                }
+
+                fn divisible_by_2(self) -> bool {
+                    self.unsigned_abs().divisible_by_2()
+                }
+
+                fn divisible_by_3(self) -> bool {
+                    self.unsigned_abs().divisible_by_3()
+                }
+
+                fn divisible_by_4(self) -> bool {
+                    self.unsigned_abs().divisible_by_4()
+                }
+
+                fn divisible_by_5(self) -> bool {
+                    self.unsigned_abs().divisible_by_5()
+                }
+
+                fn divisible_by_6(self) -> bool {
+                    self.unsigned_abs().divisible_by_6()
+                }
+
+                fn divisible_by_7(self) -> bool {
+                    self.unsigned_abs().divisible_by_7()
+                }
+
+                fn divisible_by_8(self) -> bool {
+                    self.unsigned_abs().divisible_by_8()
+                }
+
+                fn divisible_by_9(self) -> bool {
+                    self.unsigned_abs().divisible_by_9()
+                }
+
+                fn divisible_by_10(self) -> bool {
+                    self.unsigned_abs().divisible_by_10()
+                }
+
+                fn divisible_by_11(self) -> bool {
+                    self.unsigned_abs().divisible_by_11()
+                }
+
+                fn divisible_by_12(self) -> bool {
+                    self.unsigned_abs().divisible_by_12()
+                }
+            }
+        )*
+    };
+}
+
+// `usize` is at most 64 bits wide on every supported target, so widening any
+// of these to `u64` is lossless.
+impl_divisible_by_unsigned!(u8, u16, u32, u64, usize);
+impl_divisible_by_signed!(i8, i16, i32, i64, i128, isize);
+
+// Numbers wider than `u64` are first brought into range with a single step of
+// the digit algorithm, after which the `u64` rules take over.
+impl DivisibleBy for u128 {
+    fn divisible_by_0(self) -> bool {
+        false
+    }
+
+    fn divisible_by_1(self) -> bool {
+        true
+    }
+
+    fn divisible_by_2(self) -> bool {
+        divisible_by_2(last_digit(self))
+    }
+
+    fn divisible_by_3(self) -> bool {
+        divisible_by_3(digit_sum(self))
+    }
+
+    fn divisible_by_4(self) -> bool {
+        if self.divisible_by_2() {
+            (self >> 1).divisible_by_2()
+        } else {
+            false
+        }
+    }
+
+    fn divisible_by_5(self) -> bool {
+        divisible_by_5(last_digit(self))
+    }
+
+    fn divisible_by_6(self) -> bool {
+        self.divisible_by_2() && self.divisible_by_3()
+    }
+
+    fn divisible_by_7(self) -> bool {
+        match u64::try_from(self) {
+            Ok(n) => divisible_by_7(n),
+            Err(_) => {
+                let next_n = u128::from(last_digit(self) * 5) + self / 10;
+                next_n.divisible_by_7()
+            }
+        }
+    }
+
+    fn divisible_by_8(self) -> bool {
+        if self.divisible_by_2() {
+            (self >> 1).divisible_by_4()
+        } else {
+            false
+        }
+    }
+
+    fn divisible_by_9(self) -> bool {
+        divisible_by_9(digit_sum(self))
+    }
+
+    fn divisible_by_10(self) -> bool {
+        divisible_by_10(last_digit(self))
+    }
+
+    fn divisible_by_11(self) -> bool {
+        divisible_by_11(alternating_digit_sum(self).unsigned_abs())
+    }
+
+    fn divisible_by_12(self) -> bool {
+        self.divisible_by_3() && self.divisible_by_4()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(u128::MAX)]
+    #[case(u128::MAX - 2)]
+    #[case(u128::from(u64::MAX) + 1)]
+    fn wide_is_not_divisible_by_7(#[case] n: u128) {
+        assert!(!n.divisible_by_7());
+    }
+
+    #[rstest]
+    #[case(u128::MAX - 3)]
+    #[case(u128::from(u64::MAX) + 6)]
+    #[case(7 * (u128::MAX / 7))]
+    fn wide_is_divisible_by_7(#[case] n: u128) {
+        assert!(n.divisible_by_7());
+    }
+
+    #[rstest]
+    #[case(i8::MIN)]
+    #[case(-8)]
+    #[case(0)]
+    #[case(120)]
+    fn narrow_is_divisible_by_8(#[case] n: i8) {
+        assert!(n.divisible_by_8());
+    }
+
+    #[rstest]
+    #[case(i128::MIN)]
+    #[case(i128::MIN + 12)]
+    #[case(-(1 << 100))]
+    fn wide_signed_is_divisible_by_4(#[case] n: i128) {
+        assert!(n.divisible_by_4());
+    }
+
+    #[proptest]
+    fn u8_agrees_with_u64(n: u8) {
+        let m = u64::from(n);
+        assert_eq!(n.divisible_by_3(), divisible_by_3(m));
+        assert_eq!(n.divisible_by_7(), divisible_by_7(m));
+        assert_eq!(n.divisible_by_8(), divisible_by_8(m));
+        assert_eq!(n.divisible_by_11(), divisible_by_11(m));
+    }
+
+    #[proptest]
+    fn u32_agrees_with_u64(n: u32) {
+        let m = u64::from(n);
+        assert_eq!(n.divisible_by_4(), divisible_by_4(m));
+        assert_eq!(n.divisible_by_6(), divisible_by_6(m));
+        assert_eq!(n.divisible_by_9(), divisible_by_9(m));
+        assert_eq!(n.divisible_by_12(), divisible_by_12(m));
+    }
+
+    #[proptest]
+    fn usize_agrees_with_u64(n: usize) {
+        let m = n as u64;
+        assert_eq!(n.divisible_by_2(), divisible_by_2(m));
+        assert_eq!(n.divisible_by_5(), divisible_by_5(m));
+        assert_eq!(n.divisible_by_10(), divisible_by_10(m));
+    }
+
+    #[proptest]
+    fn u128_agrees_with_remainder(n: u128) {
+        assert!(!n.divisible_by_0());
+        assert!(n.divisible_by_1());
+        assert_eq!(n.divisible_by_2(), n % 2 == 0);
+        assert_eq!(n.divisible_by_3(), n % 3 == 0);
+        assert_eq!(n.divisible_by_4(), n % 4 == 0);
+        assert_eq!(n.divisible_by_5(), n % 5 == 0);
+        assert_eq!(n.divisible_by_6(), n % 6 == 0);
+        assert_eq!(n.divisible_by_7(), n % 7 == 0);
+        assert_eq!(n.divisible_by_8(), n % 8 == 0);
+        assert_eq!(n.divisible_by_9(), n % 9 == 0);
+        assert_eq!(n.divisible_by_10(), n % 10 == 0);
+        assert_eq!(n.divisible_by_11(), n % 11 == 0);
+        assert_eq!(n.divisible_by_12(), n % 12 == 0);
+    }
+
+    #[proptest]
+    fn signed_agrees_with_magnitude(n: i64) {
+        let m = n.unsigned_abs();
+        assert_eq!(n.divisible_by_3(), divisible_by_3(m));
+        assert_eq!(n.divisible_by_7(), divisible_by_7(m));
+        assert_eq!(n.divisible_by_11(), divisible_by_11(m));
+    }
+
+    #[proptest]
+    fn i128_agrees_with_remainder(n: i128) {
+        assert_eq!(n.divisible_by_7(), n % 7 == 0);
+        assert_eq!(n.divisible_by_8(), n % 8 == 0);
+        assert_eq!(n.divisible_by_11(), n % 11 == 0);
+        assert_eq!(n.divisible_by_12(), n % 12 == 0);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    pub fn check_u8_divisible_by_8() {
+        let n: u8 = kani::any();
+        assert_eq!(n.divisible_by_8(), n % 8 == 0);
+    }
+
+    #[kani::proof]
+    pub fn check_i8_divisible_by_4() {
+        let n: i8 = kani::any();
+        assert_eq!(n.divisible_by_4(), n % 4 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_u128_divisible_by_3() {
+        let n: u128 = kani::any();
+        assert_eq!(n.divisible_by_3(), n % 3 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_i128_divisible_by_11() {
+        let n: i128 = kani::any();
+        assert_eq!(n.divisible_by_11(), n % 11 == 0);
+    }
+}
@@ -1,16 +1,167 @@
 //! # Divisible by
 //!
-//! Provides functions to check for divisibility by the numbers 0 through 12
+//! Provides functions to check for divisibility by the numbers 0 through 100
 //! with algorithms based on the video "Why 7 is Weird" by [Numberphile].
 //!
+//! ## Rules
+//!
+//! Every divisor has its own function, such as [`divisible_by_7`], and
+//! [`divisible_by`] picks the rule when the divisor is only known at runtime.
+//! The rules are `const fn`, so constants can be checked at compile time with
+//! [`const_assert_divisible`].
+//!
+//! - [`try_divisible_by`] reports a divisor of zero (0) as an error.
+//! - Functions such as [`remainder_by_7`] return the remainder a rule computes,
+//!   which [`next_multiple`] and [`multiples_in`] use to find multiples nearby.
+//! - [`explain`] shows every step a rule takes to reach its verdict.
+//! - [`RuleRegistry`] lists the rules as [`Rule`] values that can be replaced,
+//!   and [`DerivedRule`] derives a rule like the one for seven for any divisor
+//!   that is coprime to ten (10).
+//! - [`Digits`], [`digit_sum`], [`alternating_digit_sum`] and [`last_digit`]
+//!   expose the digit arithmetic of the rules for writing new ones.
+//!
+//! ## Other inputs
+//!
+//! - [`DivisibleBy`] extends the checks to every primitive integer type, and
+//!   the [`signed`] module has signed variants of every check.
+//! - Variants such as [`divisible_by_7_str`] check numbers of any length
+//!   written in decimal.
+//! - [`DivisibilityState`] checks numbers that do not even fit in memory one
+//!   digit at a time.
+//! - The [`radix`] module checks numbers written in other bases, and the
+//!   [`binary`] module checks bits with masks.
+//!
+//! ## Composition and batches
+//!
+//! Rules for any other divisor are composed from the rules of its prime power
+//! factors with [`Composition`]. [`divisors_up_to_12`] checks all rules for one
+//! number at once, and functions such as [`divisible_by_all`] check a slice of
+//! numbers.
+//!
+//! ## Number theory
+//!
+//! The rules for the primes below one hundred (100) drive:
+//!
+//! - [`factorize`], [`divisors`], [`divisor_count`] and [`divisor_sum`],
+//! - [`gcd_via_rules`], [`lcm_via_rules`] and [`coprime_via_rules`],
+//! - [`is_prime`], which rejects most composite numbers with
+//!   [`is_probably_composite_fast`] before testing the rest.
+//!
+//! ## Features
+//!
+//! The crate is `no_std` and every rule computes digits with arithmetic, so
+//! none of them allocate. The default `alloc` feature enables the functions
+//! that return collections or strings, such as [`factorize`] and [`explain`],
+//! and the `std` feature adds [`DivisibilityState::read_digits`].
+//!
 //! [numberphile]: https://www.youtube.com/c/numberphile
 
+#![cfg_attr(
+    not(feature = "alloc"),
+    doc = "[`Composition`]: #features",
+    doc = "[`DerivedRule`]: #features",
+    doc = "[`RuleRegistry`]: #features",
+    doc = "[`divisible_by_7_str`]: #features",
+    doc = "[`divisors`]: #features",
+    doc = "[`divisor_count`]: #features",
+    doc = "[`divisor_sum`]: #features",
+    doc = "[`explain`]: #features",
+    doc = "[`factorize`]: #features"
+)]
+#![cfg_attr(
+    not(feature = "std"),
+    doc = "[`DivisibilityState::read_digits`]: #features"
+)]
+#![cfg_attr(not(any(feature = "std", test)), no_std)]
+#![cfg_attr(docsrs, feature(doc_cfg))]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+pub mod binary;
+pub mod radix;
+pub mod signed;
+
+mod batch;
+#[cfg(feature = "alloc")]
+mod compose;
+#[cfg(feature = "alloc")]
+mod decimal;
+#[cfg(feature = "alloc")]
+mod derivation;
+mod digits;
+mod divisor_set;
+#[cfg(feature = "alloc")]
+mod explain;
+mod extended;
+#[cfg(feature = "alloc")]
+mod factorization;
+mod fallible;
+mod gcd;
+mod integers;
+mod multiples;
+mod powers;
+mod primality;
+mod rule;
+mod stream;
 mod utils;
 
-use utils::{alternating_digit_sum, digit_sum, last_digit};
+pub use batch::{
+    count_divisible_by, divisible_by_0_all, divisible_by_10_all, divisible_by_11_all,
+    divisible_by_12_all, divisible_by_1_all, divisible_by_2_all, divisible_by_3_all,
+    divisible_by_4_all, divisible_by_5_all, divisible_by_6_all, divisible_by_7_all,
+    divisible_by_8_all, divisible_by_9_all, divisible_by_all, divisible_by_bits,
+    filter_divisible_by,
+};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use compose::Composition;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use decimal::{
+    divisible_by_0_str, divisible_by_10_str, divisible_by_11_str, divisible_by_12_str,
+    divisible_by_1_str, divisible_by_2_str, divisible_by_3_str, divisible_by_4_str,
+    divisible_by_5_str, divisible_by_6_str, divisible_by_7_str, divisible_by_8_str,
+    divisible_by_9_str, ParseError,
+};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use derivation::DerivedRule;
+pub use digits::{alternating_digit_sum, digit_sum, digits, last_digit, Digits};
+pub use divisor_set::{divisors_up_to_12, DivisorSet, DivisorSetIter};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use explain::{explain, Step, Trace};
+pub use extended::*;
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use factorization::{divisor_count, divisor_sum, divisors, factorize};
+pub use fallible::{try_divisible_by, DivisibilityError, ZeroPolicy};
+pub use gcd::{common_divisors, coprime_via_rules, gcd_via_rules, lcm_via_rules};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use gcd::{common_divisors_str, coprime_via_rules_str, gcd_via_rules_str, lcm_via_rules_str};
+pub use integers::DivisibleBy;
+pub use multiples::{count_multiples, multiples_in, next_multiple, prev_multiple, Multiples};
+pub use powers::{divisible_by_pow10, divisible_by_pow2, divisible_by_pow5};
+pub use primality::{is_prime, is_probably_composite_fast};
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+pub use rule::RuleRegistry;
+pub use rule::{BuiltinRule, Rule, BUILTIN_RULES};
+pub use stream::{DivisibilityState, STATE_SIZE};
+
+use utils::{
+    alternating_block_sum, long_division_remainder, osculate_with_steps, split_last_digit,
+    stable_alternating_digit_sum,
+};
 
 /// Determines if the provided number is divisible by zero (0).
 ///
+/// No number is divisible by zero here, not even zero itself. Use
+/// [`try_divisible_by`] to report a zero divisor as an error instead, or a
+/// [`ZeroPolicy`] to choose another meaning.
+///
 /// # Examples
 ///
 /// ```
@@ -18,7 +169,7 @@ use utils::{alternating_digit_sum, digit_sum, last_digit};
 /// let any_number = 42;
 /// assert!(!divisible_by_0(any_number));
 /// ```
-pub fn divisible_by_0(_n: u64) -> bool {
+pub const fn divisible_by_0(_n: u64) -> bool {
     false
 }
 
@@ -31,7 +182,7 @@ pub fn divisible_by_0(_n: u64) -> bool {
 /// let any_number = 42;
 /// assert!(divisible_by_1(any_number));
 /// ```
-pub fn divisible_by_1(_n: u64) -> bool {
+pub const fn divisible_by_1(_n: u64) -> bool {
     true
 }
 
@@ -44,7 +195,7 @@ pub fn divisible_by_1(_n: u64) -> bool {
 /// assert!(divisible_by_2(42));
 /// assert!(!divisible_by_2(9));
 /// ```
-pub fn divisible_by_2(n: u64) -> bool {
+pub const fn divisible_by_2(n: u64) -> bool {
     matches!(last_digit(n), 0 | 2 | 4 | 6 | 8)
 }
 
@@ -57,7 +208,7 @@ pub fn divisible_by_2(n: u64) -> bool {
 /// assert!(divisible_by_3(9));
 /// assert!(!divisible_by_3(8));
 /// ```
-pub fn divisible_by_3(n: u64) -> bool {
+pub const fn divisible_by_3(n: u64) -> bool {
     if n >= 10 {
         divisible_by_3(digit_sum(n))
     } else {
@@ -74,7 +225,7 @@ pub fn divisible_by_3(n: u64) -> bool {
 /// assert!(divisible_by_4(8));
 /// assert!(!divisible_by_4(15));
 /// ```
-pub fn divisible_by_4(n: u64) -> bool {
+pub const fn divisible_by_4(n: u64) -> bool {
     if divisible_by_2(n) {
         divisible_by_2(n >> 1)
     } else {
@@ -91,7 +242,7 @@ pub fn divisible_by_4(n: u64) -> bool {
 /// assert!(divisible_by_5(15));
 /// assert!(!divisible_by_5(12));
 /// ```
-pub fn divisible_by_5(n: u64) -> bool {
+pub const fn divisible_by_5(n: u64) -> bool {
     matches!(last_digit(n), 0 | 5)
 }
 
@@ -104,7 +255,7 @@ pub fn divisible_by_5(n: u64) -> bool {
 /// assert!(divisible_by_6(12));
 /// assert!(!divisible_by_6(21));
 /// ```
-pub fn divisible_by_6(n: u64) -> bool {
+pub const fn divisible_by_6(n: u64) -> bool {
     divisible_by_2(n) && divisible_by_3(n)
 }
 
@@ -117,15 +268,10 @@ pub fn divisible_by_6(n: u64) -> bool {
 /// assert!(divisible_by_7(21));
 /// assert!(!divisible_by_7(16));
 /// ```
-pub fn divisible_by_7(n: u64) -> bool {
+pub const fn divisible_by_7(n: u64) -> bool {
     if n > 50 {
-        let n_as_str = n.to_string();
-        let n_len = n_as_str.len();
-
-        let rest_str = unsafe { n_as_str.get(0..n_len - 1).unwrap_unchecked() };
-        let rest = unsafe { rest_str.parse::<u64>().unwrap_unchecked() };
-
-        let next_n = (last_digit(n) * 5) + rest;
+        let (rest, last) = split_last_digit(n);
+        let next_n = (last * 5) + rest;
         divisible_by_7(next_n)
     } else {
         matches!(n, 0 | 7 | 14 | 21 | 28 | 35 | 42 | 49)
@@ -141,7 +287,7 @@ pub fn divisible_by_7(n: u64) -> bool {
 /// assert!(divisible_by_8(16));
 /// assert!(!divisible_by_8(18));
 /// ```
-pub fn divisible_by_8(n: u64) -> bool {
+pub const fn divisible_by_8(n: u64) -> bool {
     if divisible_by_2(n) {
         divisible_by_4(n >> 1)
     } else {
@@ -158,7 +304,7 @@ pub fn divisible_by_8(n: u64) -> bool {
 /// assert!(divisible_by_9(18));
 /// assert!(!divisible_by_9(20));
 /// ```
-pub fn divisible_by_9(n: u64) -> bool {
+pub const fn divisible_by_9(n: u64) -> bool {
     if n >= 10 {
         divisible_by_9(digit_sum(n))
     } else {
@@ -175,7 +321,7 @@ pub fn divisible_by_9(n: u64) -> bool {
 /// assert!(divisible_by_10(20));
 /// assert!(!divisible_by_10(22));
 /// ```
-pub fn divisible_by_10(n: u64) -> bool {
+pub const fn divisible_by_10(n: u64) -> bool {
     last_digit(n) == 0
 }
 
@@ -188,7 +334,7 @@ pub fn divisible_by_10(n: u64) -> bool {
 /// assert!(divisible_by_11(22));
 /// assert!(!divisible_by_11(36));
 /// ```
-pub fn divisible_by_11(n: u64) -> bool {
+pub const fn divisible_by_11(n: u64) -> bool {
     if n >= 11 {
         divisible_by_11(alternating_digit_sum(n).unsigned_abs())
     } else {
@@ -205,10 +351,469 @@ pub fn divisible_by_11(n: u64) -> bool {
 /// assert!(divisible_by_12(36));
 /// assert!(!divisible_by_12(42));
 /// ```
-pub fn divisible_by_12(n: u64) -> bool {
+pub const fn divisible_by_12(n: u64) -> bool {
     divisible_by_3(n) && divisible_by_4(n)
 }
 
+/// Determines if the provided number is divisible by seven (7).
+///
+/// Since 7 * 11 * 13 = 1001, the alternating sum of the blocks of three digits
+/// of a number is divisible by each of these exactly when the number itself
+/// is, see [`divisible_by_1001_blocks`]. The sum has at most four digits, leaving
+/// only a few steps for [`divisible_by_7`].
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_7_blocks};
+/// assert!(divisible_by_7_blocks(434434));
+/// assert!(!divisible_by_7_blocks(434435));
+/// ```
+pub const fn divisible_by_7_blocks(n: u64) -> bool {
+    divisible_by_7(reduce_by_1001(n))
+}
+
+/// Determines if the provided number is divisible by eleven (11).
+///
+/// Like [`divisible_by_7_blocks`], using the alternating sum of the blocks of
+/// three digits of the number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_11_blocks};
+/// assert!(divisible_by_11_blocks(918082));
+/// assert!(!divisible_by_11_blocks(918083));
+/// ```
+pub const fn divisible_by_11_blocks(n: u64) -> bool {
+    divisible_by_11(reduce_by_1001(n))
+}
+
+/// Determines if the provided number is divisible by thirteen (13).
+///
+/// Like [`divisible_by_7_blocks`], using the alternating sum of the blocks of
+/// three digits of the number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_13_blocks};
+/// assert!(divisible_by_13_blocks(2041));
+/// assert!(!divisible_by_13_blocks(2042));
+/// ```
+pub const fn divisible_by_13_blocks(n: u64) -> bool {
+    divisible_by_13(reduce_by_1001(n))
+}
+
+/// Determines if the provided number is divisible by seventy-seven (77).
+///
+/// Like [`divisible_by_7_blocks`], using the alternating sum of the blocks of
+/// three digits of the number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_77_blocks};
+/// assert!(divisible_by_77_blocks(77077));
+/// assert!(!divisible_by_77_blocks(77155));
+/// ```
+pub const fn divisible_by_77_blocks(n: u64) -> bool {
+    let reduced = reduce_by_1001(n);
+    divisible_by_7(reduced) && divisible_by_11(reduced)
+}
+
+/// Determines if the provided number is divisible by ninety-one (91).
+///
+/// Like [`divisible_by_7_blocks`], using the alternating sum of the blocks of
+/// three digits of the number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_91_blocks};
+/// assert!(divisible_by_91_blocks(112294));
+/// assert!(!divisible_by_91_blocks(112301));
+/// ```
+pub const fn divisible_by_91_blocks(n: u64) -> bool {
+    let reduced = reduce_by_1001(n);
+    divisible_by_7(reduced) && divisible_by_13(reduced)
+}
+
+/// Determines if the provided number is divisible by one hundred forty-three (143).
+///
+/// Like [`divisible_by_7_blocks`], using the alternating sum of the blocks of
+/// three digits of the number.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_143_blocks};
+/// assert!(divisible_by_143_blocks(142857));
+/// assert!(!divisible_by_143_blocks(142868));
+/// ```
+pub const fn divisible_by_143_blocks(n: u64) -> bool {
+    let reduced = reduce_by_1001(n);
+    divisible_by_11(reduced) && divisible_by_13(reduced)
+}
+
+/// Determines if the provided number is divisible by one thousand and one (1001).
+///
+/// Since 1000 is one less than 1001, the alternating sum of the blocks of
+/// three digits of a number, starting from the last block, has the same
+/// remainder by 1001 as the number itself. For example, 12357345 becomes
+/// 345 - 357 + 12 = 0.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_1001_blocks};
+/// assert!(divisible_by_1001_blocks(12357345));
+/// assert!(!divisible_by_1001_blocks(12357358));
+/// ```
+pub const fn divisible_by_1001_blocks(n: u64) -> bool {
+    matches!(reduce_by_1001(n), 0 | 1001 | 2002 | 3003)
+}
+
+const fn reduce_by_1001(n: u64) -> u64 {
+    alternating_block_sum(n, 3).unsigned_abs() as u64
+}
+
+/// Determines if the provided number is divisible by the provided divisor.
+///
+/// Divisors from zero (0) through one hundred (100) are routed to their
+/// dedicated rule, e.g. [`divisible_by_7`], and powers of two (2) and five (5)
+/// to [`divisible_by_pow2`] and [`divisible_by_pow5`]. Any other divisor is
+/// checked using long division, processing the number one digit at a time.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by};
+/// assert!(divisible_by(42, 7));
+/// assert!(divisible_by(1309, 17));
+/// assert!(!divisible_by(1310, 17));
+/// assert!(divisible_by(1001 * 1009, 1009));
+/// ```
+pub const fn divisible_by(n: u64, d: u64) -> bool {
+    match d {
+        0 => divisible_by_0(n),
+        1 => divisible_by_1(n),
+        2 => divisible_by_2(n),
+        3 => divisible_by_3(n),
+        4 => divisible_by_4(n),
+        5 => divisible_by_5(n),
+        6 => divisible_by_6(n),
+        7 => divisible_by_7(n),
+        8 => divisible_by_8(n),
+        9 => divisible_by_9(n),
+        10 => divisible_by_10(n),
+        11 => divisible_by_11(n),
+        12 => divisible_by_12(n),
+        13..=100 => extended::divisible_by_13_to_100(n, d),
+        _ if d.is_power_of_two() => divisible_by_pow2(n, d.trailing_zeros()),
+        _ => match powers::pow5_exponent(d) {
+            Some(k) => divisible_by_pow5(n, k),
+            None => long_division_remainder(n, d) == 0,
+        },
+    }
+}
+
+/// Fails the build if the provided number is not divisible by the provided
+/// divisor, both of which must be constant expressions.
+///
+/// The check uses [`divisible_by`], which like every `divisible_by_*` function
+/// can be evaluated at compile time. Both values may have any integer type,
+/// but the build also fails if either of them does not fit in a `u64`, so
+/// they are never truncated or wrapped.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{const_assert_divisible};
+/// const BUFFER_SIZE: usize = 4096;
+/// const_assert_divisible!(BUFFER_SIZE, 8);
+/// const_assert_divisible!(1001, 7);
+/// const_assert_divisible!(10_000_000_000, 8);
+/// ```
+///
+/// ```compile_fail
+/// # use divisible_by::{const_assert_divisible};
+/// const BUFFER_SIZE: usize = 4100;
+/// const_assert_divisible!(BUFFER_SIZE, 12);
+/// ```
+///
+/// ```compile_fail
+/// # use divisible_by::{const_assert_divisible};
+/// const_assert_divisible!(-7, 7);
+/// ```
+///
+/// ```compile_fail
+/// # use divisible_by::{const_assert_divisible};
+/// const_assert_divisible!(1u128 << 64, 3);
+/// ```
+///
+/// ```compile_fail
+/// # use divisible_by::{const_assert_divisible};
+/// const_assert_divisible!(18_446_744_073_709_551_616, 2);
+/// ```
+#[macro_export]
+macro_rules! const_assert_divisible {
+    ($n:expr, $d:expr $(,)?) => {
+        const _: () = {
+            let n = $crate::__const_u64!($n);
+            let d = $crate::__const_u64!($d);
+            ::core::assert!(
+                $crate::divisible_by(n, d),
+                ::core::concat!(
+                    ::core::stringify!($n),
+                    " is not divisible by ",
+                    ::core::stringify!($d)
+                ),
+            );
+        };
+    };
+}
+
+/// Converts a constant integer to a `u64` for [`const_assert_divisible`],
+/// failing the build if the conversion would truncate or wrap it.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __const_u64 {
+    ($value:expr) => {{
+        // An i128 holds every u64 and every negative value of the other
+        // integer types, and a literal without a suffix takes the type it is
+        // cast to, so it is not limited to an i32. The only values that wrap
+        // are u128 values from 2^127, which become negative.
+        let wide = $value as i128;
+        ::core::assert!(
+            wide >= 0 && wide <= u64::MAX as i128,
+            ::core::concat!(::core::stringify!($value), " does not fit in a u64"),
+        );
+        wide as u64
+    }};
+}
+
+/// Returns the remainder of the provided number divided by two (2).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_2};
+/// assert_eq!(remainder_by_2(42), 0);
+/// assert_eq!(remainder_by_2(9), 1);
+/// ```
+pub fn remainder_by_2(n: u64) -> u64 {
+    match last_digit(n) {
+        0 | 2 | 4 | 6 | 8 => 0,
+        _ => 1,
+    }
+}
+
+/// Returns the remainder of the provided number divided by three (3).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_3};
+/// assert_eq!(remainder_by_3(9), 0);
+/// assert_eq!(remainder_by_3(8), 2);
+/// ```
+pub fn remainder_by_3(n: u64) -> u64 {
+    if n >= 10 {
+        remainder_by_3(digit_sum(n))
+    } else {
+        match n {
+            0 | 3 | 6 | 9 => 0,
+            1 | 4 | 7 => 1,
+            _ => 2,
+        }
+    }
+}
+
+/// Returns the remainder of the provided number divided by four (4).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_4};
+/// assert_eq!(remainder_by_4(8), 0);
+/// assert_eq!(remainder_by_4(15), 3);
+/// ```
+pub fn remainder_by_4(n: u64) -> u64 {
+    remainder_by_2(n) + 2 * remainder_by_2(n >> 1)
+}
+
+/// Returns the remainder of the provided number divided by five (5).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_5};
+/// assert_eq!(remainder_by_5(15), 0);
+/// assert_eq!(remainder_by_5(12), 2);
+/// ```
+pub fn remainder_by_5(n: u64) -> u64 {
+    let digit = last_digit(n);
+    if digit >= 5 {
+        digit - 5
+    } else {
+        digit
+    }
+}
+
+/// Returns the remainder of the provided number divided by six (6).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_6};
+/// assert_eq!(remainder_by_6(12), 0);
+/// assert_eq!(remainder_by_6(23), 5);
+/// ```
+pub fn remainder_by_6(n: u64) -> u64 {
+    let remainder_2 = remainder_by_2(n);
+    let remainder_3 = remainder_by_3(n);
+    remainder_3 + 3 * remainder_by_2(remainder_2 + remainder_3)
+}
+
+/// Returns the remainder of the provided number divided by seven (7).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_7};
+/// assert_eq!(remainder_by_7(21), 0);
+/// assert_eq!(remainder_by_7(16), 2);
+/// ```
+pub fn remainder_by_7(n: u64) -> u64 {
+    // Every step of the rule multiplies the number by five (5), which is undone
+    // by multiplying by ten (10), or three (3) as 10 = 7 + 3.
+    let (rest, steps) = osculate_with_steps(n, 5);
+    (0..steps).fold(long_division_remainder(rest, 7), |remainder, _| {
+        long_division_remainder(3 * remainder, 7)
+    })
+}
+
+/// Returns the remainder of the provided number divided by eight (8).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_8};
+/// assert_eq!(remainder_by_8(16), 0);
+/// assert_eq!(remainder_by_8(21), 5);
+/// ```
+pub fn remainder_by_8(n: u64) -> u64 {
+    remainder_by_2(n) + 2 * remainder_by_4(n >> 1)
+}
+
+/// Returns the remainder of the provided number divided by nine (9).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_9};
+/// assert_eq!(remainder_by_9(18), 0);
+/// assert_eq!(remainder_by_9(20), 2);
+/// ```
+pub fn remainder_by_9(n: u64) -> u64 {
+    if n >= 10 {
+        remainder_by_9(digit_sum(n))
+    } else if n == 9 {
+        0
+    } else {
+        n
+    }
+}
+
+/// Returns the remainder of the provided number divided by ten (10).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_10};
+/// assert_eq!(remainder_by_10(20), 0);
+/// assert_eq!(remainder_by_10(22), 2);
+/// ```
+pub fn remainder_by_10(n: u64) -> u64 {
+    last_digit(n)
+}
+
+/// Returns the remainder of the provided number divided by eleven (11).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_11};
+/// assert_eq!(remainder_by_11(22), 0);
+/// assert_eq!(remainder_by_11(36), 3);
+/// ```
+pub fn remainder_by_11(n: u64) -> u64 {
+    if n >= 11 {
+        let sum = stable_alternating_digit_sum(n as u128);
+        let remainder = remainder_by_11(sum.unsigned_abs());
+        if sum < 0 && remainder > 0 {
+            11 - remainder
+        } else {
+            remainder
+        }
+    } else {
+        n
+    }
+}
+
+/// Returns the remainder of the provided number divided by twelve (12).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by_12};
+/// assert_eq!(remainder_by_12(36), 0);
+/// assert_eq!(remainder_by_12(47), 11);
+/// ```
+pub fn remainder_by_12(n: u64) -> u64 {
+    let remainder_3 = remainder_by_3(n);
+    let remainder_4 = remainder_by_4(n);
+    remainder_4 + 4 * remainder_by_3(remainder_3 + 2 * remainder_4)
+}
+
+/// Returns the remainder of the provided number divided by the provided
+/// divisor.
+///
+/// Divisors from two (2) through twelve (12) are routed to their dedicated
+/// rule, e.g. [`remainder_by_7`]. Any other divisor is handled using long
+/// division, processing the number one digit at a time.
+///
+/// # Panics
+///
+/// Panics if the divisor is zero (0).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{remainder_by};
+/// assert_eq!(remainder_by(44, 7), 2);
+/// assert_eq!(remainder_by(1310, 17), 1);
+/// ```
+pub fn remainder_by(n: u64, d: u64) -> u64 {
+    match d {
+        0 => panic!("attempt to calculate the remainder with a divisor of zero"),
+        1 => 0,
+        2 => remainder_by_2(n),
+        3 => remainder_by_3(n),
+        4 => remainder_by_4(n),
+        5 => remainder_by_5(n),
+        6 => remainder_by_6(n),
+        7 => remainder_by_7(n),
+        8 => remainder_by_8(n),
+        9 => remainder_by_9(n),
+        10 => remainder_by_10(n),
+        11 => remainder_by_11(n),
+        12 => remainder_by_12(n),
+        _ => long_division_remainder(n, d),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,6 +821,16 @@ mod tests {
     use proptest_attr_macro::proptest;
     use rstest::rstest;
 
+    const_assert_divisible!(4096, 8);
+    const_assert_divisible!(1001, 7);
+    const_assert_divisible!(u64::MAX, 17);
+    const_assert_divisible!(u64::MAX as u128, 17u128);
+    const_assert_divisible!(91i8, 13i8);
+    const_assert_divisible!(10_000_000_000, 8);
+
+    const _: () = assert!(!divisible_by_12(4100));
+    const _: () = assert!(divisible_by_1001_blocks(1001 * 1001));
+
     #[rstest]
     #[case(0)]
     #[case(1)]
@@ -667,6 +1282,525 @@ mod tests {
         let result = divisible_by_12(n);
         assert!(result);
     }
+
+    #[rstest]
+    #[case(1)]
+    #[case(50)]
+    #[case(434435)]
+    #[case(u64::MAX)]
+    fn is_not_divisible_by_7_blocks(#[case] n: u64) {
+        let result = divisible_by_7_blocks(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(56)]
+    #[case(434434)]
+    #[case(u64::MAX - 1)]
+    fn is_divisible_by_7_blocks(#[case] n: u64) {
+        let result = divisible_by_7_blocks(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_7_blocks(base: u32) {
+        let n = 7 * (base as u64);
+        for i in 1..7 {
+            assert!(!divisible_by_7_blocks(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_7_blocks(base: u32) {
+        let n = 7 * (base as u64);
+        let result = divisible_by_7_blocks(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(12)]
+    #[case(918083)]
+    #[case(u64::MAX)]
+    fn is_not_divisible_by_11_blocks(#[case] n: u64) {
+        let result = divisible_by_11_blocks(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(22)]
+    #[case(918082)]
+    #[case(u64::MAX - 4)]
+    fn is_divisible_by_11_blocks(#[case] n: u64) {
+        let result = divisible_by_11_blocks(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_11_blocks(base: u32) {
+        let n = 11 * (base as u64);
+        for i in 1..11 {
+            assert!(!divisible_by_11_blocks(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_11_blocks(base: u32) {
+        let n = 11 * (base as u64);
+        let result = divisible_by_11_blocks(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1)]
+    #[case(14)]
+    #[case(2042)]
+    #[case(u64::MAX)]
+    fn is_not_divisible_by_13_blocks(#[case] n: u64) {
+        let result = divisible_by_13_blocks(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(26)]
+    #[case(2041)]
+    #[case(u64::MAX - 2)]
+    fn is_divisible_by_13_blocks(#[case] n: u64) {
+        let result = divisible_by_13_blocks(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_13_blocks(base: u32) {
+        let n = 13 * (base as u64);
+        for i in 1..13 {
+            assert!(!divisible_by_13_blocks(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_13_blocks(base: u32) {
+        let n = 13 * (base as u64);
+        let result = divisible_by_13_blocks(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(7)]
+    #[case(11)]
+    #[case(77078)]
+    #[case(u64::MAX)]
+    fn is_not_divisible_by_77_blocks(#[case] n: u64) {
+        let result = divisible_by_77_blocks(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1001)]
+    #[case(77077)]
+    #[case(u64::MAX - 15)]
+    fn is_divisible_by_77_blocks(#[case] n: u64) {
+        let result = divisible_by_77_blocks(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_77_blocks(base: u32) {
+        let n = 77 * (base as u64);
+        for i in 1..77 {
+            assert!(!divisible_by_77_blocks(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_77_blocks(base: u32) {
+        let n = 77 * (base as u64);
+        let result = divisible_by_77_blocks(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(7)]
+    #[case(13)]
+    #[case(112301)]
+    #[case(u64::MAX)]
+    fn is_not_divisible_by_91_blocks(#[case] n: u64) {
+        let result = divisible_by_91_blocks(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1001)]
+    #[case(112294)]
+    #[case(u64::MAX - 15)]
+    fn is_divisible_by_91_blocks(#[case] n: u64) {
+        let result = divisible_by_91_blocks(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_91_blocks(base: u32) {
+        let n = 91 * (base as u64);
+        for i in 1..91 {
+            assert!(!divisible_by_91_blocks(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_91_blocks(base: u32) {
+        let n = 91 * (base as u64);
+        let result = divisible_by_91_blocks(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(11)]
+    #[case(13)]
+    #[case(142868)]
+    #[case(u64::MAX)]
+    fn is_not_divisible_by_143_blocks(#[case] n: u64) {
+        let result = divisible_by_143_blocks(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1001)]
+    #[case(142857)]
+    #[case(u64::MAX - 15)]
+    fn is_divisible_by_143_blocks(#[case] n: u64) {
+        let result = divisible_by_143_blocks(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_143_blocks(base: u32) {
+        let n = 143 * (base as u64);
+        for i in 1..143 {
+            assert!(!divisible_by_143_blocks(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_143_blocks(base: u32) {
+        let n = 143 * (base as u64);
+        let result = divisible_by_143_blocks(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(7)]
+    #[case(143)]
+    #[case(12357358)]
+    #[case(u64::MAX)]
+    fn is_not_divisible_by_1001_blocks(#[case] n: u64) {
+        let result = divisible_by_1001_blocks(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1001)]
+    #[case(12357345)]
+    #[case(u64::MAX - 15)]
+    fn is_divisible_by_1001_blocks(#[case] n: u64) {
+        let result = divisible_by_1001_blocks(n);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_1001_blocks(base: u32) {
+        let n = 1001 * (base as u64);
+        for i in 1..1001 {
+            assert!(!divisible_by_1001_blocks(n + i));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_1001_blocks(base: u32) {
+        let n = 1001 * (base as u64);
+        let result = divisible_by_1001_blocks(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1, 0)]
+    #[case(48, 7)]
+    #[case(1310, 17)]
+    #[case(u64::MAX - 1, u64::MAX)]
+    fn is_not_divisible_by(#[case] n: u64, #[case] d: u64) {
+        let result = divisible_by(n, d);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0, 1)]
+    #[case(42, 7)]
+    #[case(1309, 17)]
+    #[case(u64::MAX, u64::MAX)]
+    fn is_divisible_by(#[case] n: u64, #[case] d: u64) {
+        let result = divisible_by(n, d);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by(base: u32, d: u16, offset: u16) {
+        let d = u64::from(d) + 2;
+        let n = d * (base as u64) + (u64::from(offset) % (d - 1)) + 1;
+        assert!(!divisible_by(n, d));
+    }
+
+    #[proptest]
+    fn are_divisible_by(base: u32, d: u16) {
+        let d = u64::from(d) + 1;
+        let n = d * (base as u64);
+        let result = divisible_by(n, d);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn divisible_by_agrees_with_the_rules(n: u64) {
+        assert_eq!(divisible_by(n, 0), divisible_by_0(n));
+        assert_eq!(divisible_by(n, 7), divisible_by_7(n));
+        assert_eq!(divisible_by(n, 12), divisible_by_12(n));
+        assert_eq!(divisible_by(n, 13), divisible_by_13(n));
+        assert_eq!(divisible_by(n, 100), divisible_by_100(n));
+        assert_eq!(divisible_by(n, 128), divisible_by_pow2(n, 7));
+        assert_eq!(divisible_by(n, 625), divisible_by_pow5(n, 4));
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(1, 1)]
+    #[case(3302049228, 0)]
+    #[case(3268551229, 1)]
+    fn is_the_remainder_by_2(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_2(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_2(base: u32) {
+        let n = 2 * (base as u64);
+        for i in 0..2 {
+            assert_eq!(remainder_by_2(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(8, 2)]
+    #[case(1213668864, 0)]
+    #[case(2397019585706911675, 1)]
+    fn is_the_remainder_by_3(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_3(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_3(base: u32) {
+        let n = 3 * (base as u64);
+        for i in 0..3 {
+            assert_eq!(remainder_by_3(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(15, 3)]
+    #[case(4815053434035945, 1)]
+    #[case(12654177670040363229, 1)]
+    fn is_the_remainder_by_4(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_4(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_4(base: u32) {
+        let n = 4 * (base as u64);
+        for i in 0..4 {
+            assert_eq!(remainder_by_4(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(4, 4)]
+    #[case(299, 4)]
+    #[case(825, 0)]
+    fn is_the_remainder_by_5(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_5(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_5(base: u32) {
+        let n = 5 * (base as u64);
+        for i in 0..5 {
+            assert_eq!(remainder_by_5(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(5, 5)]
+    #[case(616, 4)]
+    #[case(u64::MAX, 3)]
+    fn is_the_remainder_by_6(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_6(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_6(base: u32) {
+        let n = 6 * (base as u64);
+        for i in 0..6 {
+            assert_eq!(remainder_by_6(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(16, 2)]
+    #[case(6468, 0)]
+    #[case(u64::MAX, 1)]
+    fn is_the_remainder_by_7(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_7(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_7(base: u32) {
+        let n = 7 * (base as u64);
+        for i in 0..7 {
+            assert_eq!(remainder_by_7(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(7, 7)]
+    #[case(285889432707005401, 1)]
+    #[case(u64::MAX, 7)]
+    fn is_the_remainder_by_8(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_8(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_8(base: u32) {
+        let n = 8 * (base as u64);
+        for i in 0..8 {
+            assert_eq!(remainder_by_8(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(9, 0)]
+    #[case(5340001969, 1)]
+    #[case(u64::MAX, 6)]
+    fn is_the_remainder_by_9(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_9(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_9(base: u32) {
+        let n = 9 * (base as u64);
+        for i in 0..9 {
+            assert_eq!(remainder_by_9(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(9, 9)]
+    #[case(9010, 0)]
+    #[case(u64::MAX, 5)]
+    fn is_the_remainder_by_10(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_10(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_10(base: u32) {
+        let n = 10 * (base as u64);
+        for i in 0..10 {
+            assert_eq!(remainder_by_10(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(10, 10)]
+    #[case(12, 1)]
+    #[case(u64::MAX, 4)]
+    fn is_the_remainder_by_11(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_11(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_11(base: u32) {
+        let n = 11 * (base as u64);
+        for i in 0..11 {
+            assert_eq!(remainder_by_11(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(11, 11)]
+    #[case(1309027009, 1)]
+    #[case(u64::MAX, 3)]
+    fn is_the_remainder_by_12(#[case] n: u64, #[case] expected: u64) {
+        let result = remainder_by_12(n);
+        assert_eq!(result, expected);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by_12(base: u32) {
+        let n = 12 * (base as u64);
+        for i in 0..12 {
+            assert_eq!(remainder_by_12(n + i), i);
+        }
+    }
+
+    #[rstest]
+    #[case(42, 1, 0)]
+    #[case(44, 7, 2)]
+    #[case(1310, 17, 1)]
+    #[case(u64::MAX - 1, u64::MAX, u64::MAX - 1)]
+    fn is_the_remainder_by(#[case] n: u64, #[case] d: u64, #[case] expected: u64) {
+        let result = remainder_by(n, d);
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    #[should_panic]
+    fn is_not_the_remainder_by_0() {
+        remainder_by(42, 0);
+    }
+
+    #[proptest]
+    fn are_the_remainder_by(base: u32, d: u16, offset: u16) {
+        let d = u64::from(d) + 1;
+        let offset = u64::from(offset) % d;
+        let n = d * (base as u64) + offset;
+        assert_eq!(remainder_by(n, d), offset);
+    }
+
+    #[proptest]
+    fn are_divisible_when_the_remainder_is_0(n: u64, d: u8) {
+        let d = u64::from(d) + 1;
+        assert_eq!(remainder_by(n, d) == 0, divisible_by(n, d));
+    }
 }
 
 #[cfg(kani)]
@@ -756,4 +1890,134 @@ mod verification {
         let n: u64 = kani::any();
         assert_eq!(divisible_by_12(n), n % 12 == 0);
     }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_divisible_by_7_blocks() {
+        let n: u32 = kani::any();
+        assert_eq!(divisible_by_7_blocks(n.into()), n % 7 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_divisible_by_11_blocks() {
+        let n: u32 = kani::any();
+        assert_eq!(divisible_by_11_blocks(n.into()), n % 11 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_divisible_by_13_blocks() {
+        let n: u32 = kani::any();
+        assert_eq!(divisible_by_13_blocks(n.into()), n % 13 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_divisible_by_77_blocks() {
+        let n: u32 = kani::any();
+        assert_eq!(divisible_by_77_blocks(n.into()), n % 77 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_divisible_by_91_blocks() {
+        let n: u32 = kani::any();
+        assert_eq!(divisible_by_91_blocks(n.into()), n % 91 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_divisible_by_143_blocks() {
+        let n: u32 = kani::any();
+        assert_eq!(divisible_by_143_blocks(n.into()), n % 143 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(8)]
+    pub fn check_divisible_by_1001_blocks() {
+        let n: u32 = kani::any();
+        assert_eq!(divisible_by_1001_blocks(n.into()), n % 1001 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_divisible_by() {
+        let n: u16 = kani::any();
+        let d: u16 = kani::any();
+        kani::assume(d > 0);
+        assert_eq!(divisible_by(n.into(), d.into()), n % d == 0);
+    }
+
+    #[kani::proof]
+    pub fn check_remainder_by_2() {
+        let n: u16 = kani::any();
+        assert_eq!(remainder_by_2(n.into()), (n % 2).into());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_remainder_by_3() {
+        let n: u64 = kani::any();
+        assert_eq!(remainder_by_3(n), n % 3);
+    }
+
+    #[kani::proof]
+    pub fn check_remainder_by_4() {
+        let n: u16 = kani::any();
+        assert_eq!(remainder_by_4(n.into()), (n % 4).into());
+    }
+
+    #[kani::proof]
+    pub fn check_remainder_by_5() {
+        let n: u16 = kani::any();
+        assert_eq!(remainder_by_5(n.into()), (n % 5).into());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_remainder_by_6() {
+        let n: u64 = kani::any();
+        assert_eq!(remainder_by_6(n), n % 6);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_remainder_by_7() {
+        let n: u64 = kani::any();
+        assert_eq!(remainder_by_7(n), n % 7);
+    }
+
+    #[kani::proof]
+    pub fn check_remainder_by_8() {
+        let n: u16 = kani::any();
+        assert_eq!(remainder_by_8(n.into()), (n % 8).into());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_remainder_by_9() {
+        let n: u64 = kani::any();
+        assert_eq!(remainder_by_9(n), n % 9);
+    }
+
+    #[kani::proof]
+    pub fn check_remainder_by_10() {
+        let n: u16 = kani::any();
+        assert_eq!(remainder_by_10(n.into()), (n % 10).into());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_remainder_by_11() {
+        let n: u64 = kani::any();
+        assert_eq!(remainder_by_11(n), n % 11);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_remainder_by_12() {
+        let n: u64 = kani::any();
+        assert_eq!(remainder_by_12(n), n % 12);
+    }
 }
@@ -0,0 +1,310 @@
+//! Multiples of a divisor near a number or within a range.
+//!
+//! The multiples next to a number follow from its remainder as computed by
+//! [`remainder_by`], e.g. the multiple of seven (7) at or below 45 is 45 minus
+//! its remainder 3. Every function handles the multiples near [`u64::MAX`]
+//! explicitly instead of overflowing.
+
+use core::iter::FusedIterator;
+use core::ops::{Bound, RangeBounds};
+
+use crate::remainder_by;
+
+/// Returns the smallest multiple of the provided divisor that is at least the
+/// provided number, or `None` if it does not fit in a `u64` or the divisor is
+/// zero (0).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{next_multiple};
+/// assert_eq!(next_multiple(45, 7), Some(49));
+/// assert_eq!(next_multiple(49, 7), Some(49));
+/// assert_eq!(next_multiple(u64::MAX, 2), None);
+/// ```
+pub fn next_multiple(n: u64, d: u64) -> Option<u64> {
+    if d == 0 {
+        return None;
+    }
+
+    match remainder_by(n, d) {
+        0 => Some(n),
+        remainder => n.checked_add(d - remainder),
+    }
+}
+
+/// Returns the largest multiple of the provided divisor that is at most the
+/// provided number, or `None` if the divisor is zero (0).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{prev_multiple};
+/// assert_eq!(prev_multiple(45, 7), Some(42));
+/// assert_eq!(prev_multiple(42, 7), Some(42));
+/// assert_eq!(prev_multiple(6, 7), Some(0));
+/// ```
+pub fn prev_multiple(n: u64, d: u64) -> Option<u64> {
+    if d == 0 {
+        return None;
+    }
+
+    Some(n - remainder_by(n, d))
+}
+
+/// Returns an iterator over the multiples of the provided divisor within the
+/// provided range, in ascending order. There are no multiples of zero (0).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{multiples_in};
+/// let multiples: Vec<u64> = multiples_in(10..=30, 7).collect();
+/// assert_eq!(multiples, [14, 21, 28]);
+///
+/// let last: Vec<u64> = multiples_in(u64::MAX - 10.., 5).rev().collect();
+/// assert_eq!(last, [u64::MAX, u64::MAX - 5, u64::MAX - 10]);
+/// ```
+pub fn multiples_in<R: RangeBounds<u64>>(range: R, d: u64) -> Multiples {
+    let remaining = bounds(range).and_then(|(start, end)| {
+        let first = next_multiple(start, d)?;
+        let last = prev_multiple(end, d)?;
+        (first <= last).then_some((first, last))
+    });
+
+    Multiples { remaining, d }
+}
+
+/// Returns the number of multiples of the provided divisor within the provided
+/// range. There are no multiples of zero (0).
+///
+/// The count is a `u128` since every one of the 2^64 numbers in `0..=u64::MAX`
+/// is a multiple of one (1).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{count_multiples};
+/// assert_eq!(count_multiples(1..=100, 12), 8);
+/// assert_eq!(count_multiples(.., 1), 1 << 64);
+/// assert_eq!(count_multiples(.., 0), 0);
+/// ```
+pub fn count_multiples<R: RangeBounds<u64>>(range: R, d: u64) -> u128 {
+    multiples_in(range, d).count_remaining()
+}
+
+/// An iterator over the multiples of a divisor within a range, see
+/// [`multiples_in`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Multiples {
+    remaining: Option<(u64, u64)>,
+    d: u64,
+}
+
+impl Multiples {
+    fn count_remaining(&self) -> u128 {
+        self.remaining
+            .map_or(0, |(first, last)| u128::from((last - first) / self.d) + 1)
+    }
+}
+
+impl Iterator for Multiples {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let (first, last) = self.remaining?;
+        self.remaining = (first < last).then(|| (first + self.d, last));
+        Some(first)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.count_remaining()) {
+            Ok(count) => (count, Some(count)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    fn count(self) -> usize {
+        usize::try_from(self.count_remaining()).expect("the count does not fit in a usize")
+    }
+
+    fn last(mut self) -> Option<u64> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for Multiples {
+    fn next_back(&mut self) -> Option<u64> {
+        let (first, last) = self.remaining?;
+        self.remaining = (first < last).then(|| (first, last - self.d));
+        Some(last)
+    }
+}
+
+impl FusedIterator for Multiples {}
+
+fn bounds<R: RangeBounds<u64>>(range: R) -> Option<(u64, u64)> {
+    let start = match range.start_bound() {
+        Bound::Included(&start) => start,
+        Bound::Excluded(&start) => start.checked_add(1)?,
+        Bound::Unbounded => 0,
+    };
+    let end = match range.end_bound() {
+        Bound::Included(&end) => end,
+        Bound::Excluded(&end) => end.checked_sub(1)?,
+        Bound::Unbounded => u64::MAX,
+    };
+
+    (start <= end).then_some((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, 7, Some(0))]
+    #[case(45, 7, Some(49))]
+    #[case(49, 7, Some(49))]
+    #[case(u64::MAX, 1, Some(u64::MAX))]
+    #[case(u64::MAX, 5, Some(u64::MAX))]
+    #[case(u64::MAX, 2, None)]
+    #[case(1, u64::MAX, Some(u64::MAX))]
+    #[case(42, 0, None)]
+    fn is_the_next_multiple(#[case] n: u64, #[case] d: u64, #[case] expected: Option<u64>) {
+        assert_eq!(next_multiple(n, d), expected);
+    }
+
+    #[rstest]
+    #[case(0, 7, Some(0))]
+    #[case(45, 7, Some(42))]
+    #[case(6, 7, Some(0))]
+    #[case(u64::MAX, 2, Some(u64::MAX - 1))]
+    #[case(u64::MAX - 1, u64::MAX, Some(0))]
+    #[case(42, 0, None)]
+    fn is_the_prev_multiple(#[case] n: u64, #[case] d: u64, #[case] expected: Option<u64>) {
+        assert_eq!(prev_multiple(n, d), expected);
+    }
+
+    #[proptest]
+    fn are_the_nearest_multiples(n: u64, d: u32) {
+        let d = u64::from(d) + 1;
+        let prev = prev_multiple(n, d).unwrap();
+        assert_eq!(prev % d, 0);
+        assert!(prev <= n && n - prev < d);
+
+        let expected = u128::from(prev) + if prev == n { 0 } else { u128::from(d) };
+        let next = next_multiple(n, d).map(u128::from);
+        assert_eq!(next, (expected <= u128::from(u64::MAX)).then_some(expected));
+    }
+
+    #[rstest]
+    #[case(10..=30, 7, vec![14, 21, 28])]
+    #[case(14..28, 7, vec![14, 21])]
+    #[case(15..20, 7, vec![])]
+    #[case(0..0, 7, vec![])]
+    #[case(0..=0, 7, vec![0])]
+    #[case(0..=100, 0, vec![])]
+    #[case(u64::MAX - 1..=u64::MAX, 1, vec![u64::MAX - 1, u64::MAX])]
+    fn are_the_multiples_in(
+        #[case] range: impl RangeBounds<u64>,
+        #[case] d: u64,
+        #[case] expected: Vec<u64>,
+    ) {
+        let multiples: Vec<u64> = multiples_in(range, d).collect();
+        assert_eq!(multiples, expected);
+    }
+
+    #[test]
+    fn are_the_multiples_in_excluded_bounds() {
+        let range = (Bound::Excluded(u64::MAX), Bound::Unbounded);
+        assert_eq!(multiples_in(range, 1).next(), None);
+
+        let range = (Bound::Excluded(14), Bound::Excluded(28));
+        let multiples: Vec<u64> = multiples_in(range, 7).collect();
+        assert_eq!(multiples, [21]);
+    }
+
+    #[test]
+    fn are_the_multiples_in_up_to_u64_max() {
+        let mut multiples = multiples_in(u64::MAX - 6.., 3);
+        assert_eq!(multiples.next(), Some(u64::MAX - 6));
+        assert_eq!(multiples.next_back(), Some(u64::MAX));
+        assert_eq!(multiples.next(), Some(u64::MAX - 3));
+        assert_eq!(multiples.next(), None);
+        assert_eq!(multiples.next_back(), None);
+    }
+
+    #[proptest]
+    fn are_the_multiples_in_like_filtering(start: u16, len: u8, d: u8) {
+        let range = u64::from(start)..u64::from(start) + u64::from(len);
+        let expected: Vec<u64> = range
+            .clone()
+            .filter(|n| n.checked_rem(d.into()) == Some(0))
+            .collect();
+        let multiples = multiples_in(range.clone(), d.into());
+        assert_eq!(multiples.size_hint().1, Some(expected.len()));
+        assert_eq!(multiples.clone().collect::<Vec<u64>>(), expected);
+
+        let mut reversed: Vec<u64> = multiples.rev().collect();
+        reversed.reverse();
+        assert_eq!(reversed, expected);
+    }
+
+    #[rstest]
+    #[case(1..=100, 12, 8)]
+    #[case(0..=100, 12, 9)]
+    #[case(13..24, 12, 0)]
+    #[case(.., 1, 1 << 64)]
+    #[case(.., 2, 1 << 63)]
+    #[case(.., u64::MAX, 2)]
+    #[case(.., 0, 0)]
+    fn is_the_count_of_multiples(
+        #[case] range: impl RangeBounds<u64>,
+        #[case] d: u64,
+        #[case] expected: u128,
+    ) {
+        assert_eq!(count_multiples(range, d), expected);
+    }
+
+    #[proptest]
+    fn is_the_count_of_multiples_like_the_quotients(a: u64, b: u64, d: u64) {
+        let (a, b) = (a.min(b), a.max(b));
+        let below = |n: u64| {
+            n.checked_div(d)
+                .map_or(0, |quotient| u128::from(quotient) + 1)
+        };
+        let expected = below(b) - a.checked_sub(1).map_or(0, below);
+        assert_eq!(count_multiples(a..=b, d), expected);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_next_multiple() {
+        let n: u8 = kani::any();
+        let d: u8 = kani::any();
+        kani::assume(d > 0 && d <= 12);
+        let next = next_multiple(n.into(), d.into()).unwrap();
+        assert!(next % u64::from(d) == 0);
+        assert!(next >= n.into() && next - u64::from(n) < d.into());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_prev_multiple() {
+        let n: u8 = kani::any();
+        let d: u8 = kani::any();
+        kani::assume(d > 0 && d <= 12);
+        let prev = prev_multiple(n.into(), d.into()).unwrap();
+        assert!(prev % u64::from(d) == 0);
+        assert!(prev <= n.into() && u64::from(n) - prev < d.into());
+    }
+}
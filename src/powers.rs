@@ -0,0 +1,246 @@
+//! Divisibility rules for powers of two (2), five (5) and ten (10).
+//!
+//! Since ten is divisible by both two and five, a number is divisible by the
+//! k-th power of either exactly when the number formed by its last k digits is.
+
+use crate::divisible_by_5;
+use crate::utils::last_digits;
+
+/// Determines if the provided number is divisible by two (2) to the power of
+/// `k`, by looking at its last `k` digits only.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_pow2};
+/// assert!(divisible_by_pow2(1_000_016, 4));
+/// assert!(!divisible_by_pow2(1_000_024, 4));
+/// assert!(divisible_by_pow2(1 << 63, 63));
+/// ```
+pub const fn divisible_by_pow2(n: u64, k: u32) -> bool {
+    let last = last_digits(n, k as usize);
+    last == 0 || last.trailing_zeros() >= k
+}
+
+/// Determines if the provided number is divisible by five (5) to the power of
+/// `k`, by looking at its last `k` digits only.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_pow5};
+/// assert!(divisible_by_pow5(1_000_125, 3));
+/// assert!(!divisible_by_pow5(1_000_025, 3));
+/// assert!(divisible_by_pow5(5_u64.pow(27), 27));
+/// ```
+pub const fn divisible_by_pow5(n: u64, k: u32) -> bool {
+    let mut last = last_digits(n, k as usize);
+    let mut i = 0;
+    while i < k {
+        if last == 0 {
+            return true;
+        } else if !divisible_by_5(last) {
+            return false;
+        }
+
+        last /= 5;
+        i += 1;
+    }
+
+    true
+}
+
+/// Determines if the provided number is divisible by ten (10) to the power of
+/// `k`, i.e. if its last `k` digits are all zero.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{divisible_by_pow10};
+/// assert!(divisible_by_pow10(42_000, 3));
+/// assert!(!divisible_by_pow10(42_000, 4));
+/// ```
+pub const fn divisible_by_pow10(n: u64, k: u32) -> bool {
+    last_digits(n, k as usize) == 0
+}
+
+/// Returns `k` if the provided number is five (5) to the power of `k`.
+pub(crate) const fn pow5_exponent(d: u64) -> Option<u32> {
+    let mut d = d;
+    let mut k = 0;
+    while d > 1 {
+        if !divisible_by_5(d) {
+            return None;
+        }
+
+        d /= 5;
+        k += 1;
+    }
+
+    if d == 1 {
+        Some(k)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(1_000_024, 4)]
+    #[case(1 << 62, 63)]
+    #[case(u64::MAX, 1)]
+    #[case(1 << 63, 64)]
+    #[case(1, 1000)]
+    fn is_not_divisible_by_pow2(#[case] n: u64, #[case] k: u32) {
+        let result = divisible_by_pow2(n, k);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(0, 1000)]
+    #[case(1, 0)]
+    #[case(1_000_016, 4)]
+    #[case(1 << 63, 63)]
+    #[case(u64::MAX - 63, 6)]
+    fn is_divisible_by_pow2(#[case] n: u64, #[case] k: u32) {
+        let result = divisible_by_pow2(n, k);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_pow2(base: u32, k: u8) {
+        let k = u32::from(k % 12);
+        let n = (u64::from(base) << k) + (1 << k) - 1;
+        for i in 0..(1 << k) - 1 {
+            assert!(!divisible_by_pow2(n - i, k));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_pow2(base: u32, k: u8) {
+        let k = u32::from(k % 33);
+        let n = u64::from(base) << k;
+        let result = divisible_by_pow2(n, k);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(1_000_025, 3)]
+    #[case(5_u64.pow(27), 28)]
+    #[case(1, 1000)]
+    fn is_not_divisible_by_pow5(#[case] n: u64, #[case] k: u32) {
+        let result = divisible_by_pow5(n, k);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(0, 1000)]
+    #[case(1, 0)]
+    #[case(1_000_125, 3)]
+    #[case(5_u64.pow(27), 27)]
+    #[case(2 * 5_u64.pow(27), 27)]
+    fn is_divisible_by_pow5(#[case] n: u64, #[case] k: u32) {
+        let result = divisible_by_pow5(n, k);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_not_divisible_by_pow5(base: u16, k: u8) {
+        let k = k % 6;
+        let d = 5_u64.pow(k.into());
+        let n = d * u64::from(base);
+        for i in 1..d {
+            assert!(!divisible_by_pow5(n + i, k.into()));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_pow5(base: u32, k: u8) {
+        let k = k % 14;
+        let n = 5_u64.pow(k.into()) * u64::from(base);
+        let result = divisible_by_pow5(n, k.into());
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(1, 1)]
+    #[case(42_000, 4)]
+    #[case(u64::MAX, 1)]
+    #[case(1, 1000)]
+    fn is_not_divisible_by_pow10(#[case] n: u64, #[case] k: u32) {
+        let result = divisible_by_pow10(n, k);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(0, 1000)]
+    #[case(1, 0)]
+    #[case(42_000, 3)]
+    #[case(10_u64.pow(19), 19)]
+    fn is_divisible_by_pow10(#[case] n: u64, #[case] k: u32) {
+        let result = divisible_by_pow10(n, k);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn are_divisible_by_pow10_like_pow2_and_pow5(n: u64, k: u8) {
+        let k = u32::from(k % 22);
+        let expected = divisible_by_pow2(n, k) && divisible_by_pow5(n, k);
+        assert_eq!(divisible_by_pow10(n, k), expected);
+    }
+
+    #[rstest]
+    #[case(1, Some(0))]
+    #[case(5, Some(1))]
+    #[case(125, Some(3))]
+    #[case(5_u64.pow(27), Some(27))]
+    #[case(0, None)]
+    #[case(10, None)]
+    #[case(2 * 5_u64.pow(27), None)]
+    fn pow5_exponent_returns_the_exponent(#[case] d: u64, #[case] expected: Option<u32>) {
+        assert_eq!(pow5_exponent(d), expected);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_divisible_by_pow2() {
+        let n: u16 = kani::any();
+        let k: u32 = kani::any();
+        kani::assume(k < 16);
+        assert_eq!(divisible_by_pow2(n.into(), k), n % (1 << k) == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_divisible_by_pow5() {
+        let n: u16 = kani::any();
+        let k: u32 = kani::any();
+        kani::assume(k < 5);
+        assert_eq!(divisible_by_pow5(n.into(), k), n % 5_u16.pow(k) == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_divisible_by_pow10() {
+        let n: u16 = kani::any();
+        let k: u32 = kani::any();
+        kani::assume(k < 5);
+        assert_eq!(divisible_by_pow10(n.into(), k), n % 10_u16.pow(k) == 0);
+    }
+}
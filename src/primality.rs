@@ -0,0 +1,213 @@
+//! Primality testing, with the digit rules as a quick first filter.
+//!
+//! Most composite numbers have a small prime factor, so the rules for two (2),
+//! three (3), five (5), seven (7) and eleven (11) reject about four out of
+//! five numbers before any multiplication happens. The numbers that pass the
+//! filter are tested with Miller-Rabin, which is deterministic for every `u64`
+//! with the first twelve (12) primes as witnesses.
+
+use crate::{
+    divisible_by_11, divisible_by_13, divisible_by_17, divisible_by_19, divisible_by_2,
+    divisible_by_23, divisible_by_29, divisible_by_3, divisible_by_31, divisible_by_37,
+    divisible_by_41, divisible_by_43, divisible_by_47, divisible_by_5, divisible_by_53,
+    divisible_by_59, divisible_by_61, divisible_by_67, divisible_by_7, divisible_by_71,
+    divisible_by_73, divisible_by_79, divisible_by_83, divisible_by_89, divisible_by_97,
+};
+
+/// A prime with its digit rule.
+pub(crate) type PrimeRule = (u64, fn(u64) -> bool);
+
+/// The primes below one hundred (100) with their digit rules.
+pub(crate) const SMALL_PRIMES: [PrimeRule; 25] = [
+    (2, divisible_by_2),
+    (3, divisible_by_3),
+    (5, divisible_by_5),
+    (7, divisible_by_7),
+    (11, divisible_by_11),
+    (13, divisible_by_13),
+    (17, divisible_by_17),
+    (19, divisible_by_19),
+    (23, divisible_by_23),
+    (29, divisible_by_29),
+    (31, divisible_by_31),
+    (37, divisible_by_37),
+    (41, divisible_by_41),
+    (43, divisible_by_43),
+    (47, divisible_by_47),
+    (53, divisible_by_53),
+    (59, divisible_by_59),
+    (61, divisible_by_61),
+    (67, divisible_by_67),
+    (71, divisible_by_71),
+    (73, divisible_by_73),
+    (79, divisible_by_79),
+    (83, divisible_by_83),
+    (89, divisible_by_89),
+    (97, divisible_by_97),
+];
+
+/// The primes checked by the filter, i.e. those up to eleven (11).
+const FILTER: &[PrimeRule] = SMALL_PRIMES.split_at(5).0;
+
+/// The witnesses that make Miller-Rabin deterministic below 2^64.
+const WITNESSES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+/// Returns the smallest prime among two (2), three (3), five (5), seven (7)
+/// and eleven (11) whose rule shows that the provided number is composite, or
+/// `None` if none of them does.
+///
+/// A number that passes the filter is not necessarily prime, e.g. 169 is 13
+/// squared. Zero (0) and one (1) are neither prime nor composite and a prime
+/// is never eliminated by its own rule, so these all return `None`.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{is_probably_composite_fast};
+/// assert_eq!(is_probably_composite_fast(91), Some(7));
+/// assert_eq!(is_probably_composite_fast(1001), Some(7));
+/// assert_eq!(is_probably_composite_fast(7), None);
+/// assert_eq!(is_probably_composite_fast(169), None);
+/// ```
+pub fn is_probably_composite_fast(n: u64) -> Option<u64> {
+    if n < 2 {
+        return None;
+    }
+
+    FILTER
+        .iter()
+        .find(|&&(prime, rule)| n != prime && rule(n))
+        .map(|&(prime, _)| prime)
+}
+
+/// Determines if the provided number is prime.
+///
+/// The number is first checked with [`is_probably_composite_fast`], and the
+/// numbers that pass are tested with a deterministic Miller-Rabin test.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{is_prime};
+/// assert!(is_prime(7));
+/// assert!(is_prime(1009));
+/// assert!(!is_prime(1001));
+/// assert!(!is_prime(169));
+/// assert!(!is_prime(1));
+/// ```
+pub fn is_prime(n: u64) -> bool {
+    if n < 2 || is_probably_composite_fast(n).is_some() {
+        return false;
+    }
+
+    // Every number below 13 squared that passes the filter is prime.
+    if n < 169 {
+        return true;
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+    WITNESSES.iter().all(|&witness| {
+        let mut x = power(witness, d, n);
+        if x == 1 || x == n - 1 {
+            return true;
+        }
+
+        (1..s).any(|_| {
+            x = multiply(x, x, n);
+            x == n - 1
+        })
+    })
+}
+
+fn multiply(a: u64, b: u64, m: u64) -> u64 {
+    (u128::from(a) * u128::from(b) % u128::from(m)) as u64
+}
+
+fn power(base: u64, mut exponent: u64, m: u64) -> u64 {
+    let (mut base, mut result) = (base % m, 1);
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = multiply(result, base, m);
+        }
+        base = multiply(base, base, m);
+        exponent >>= 1;
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0, None)]
+    #[case(1, None)]
+    #[case(2, None)]
+    #[case(4, Some(2))]
+    #[case(11, None)]
+    #[case(15, Some(3))]
+    #[case(91, Some(7))]
+    #[case(121, Some(11))]
+    #[case(169, None)]
+    #[case(u64::MAX, Some(3))]
+    fn is_eliminated_by(#[case] n: u64, #[case] expected: Option<u64>) {
+        assert_eq!(is_probably_composite_fast(n), expected);
+    }
+
+    #[proptest]
+    fn is_eliminated_by_a_factor(n: u64) {
+        if let Some(prime) = is_probably_composite_fast(n) {
+            assert!(n % prime == 0 && n > prime);
+        }
+    }
+
+    #[rstest]
+    #[case(2)]
+    #[case(13)]
+    #[case(1009)]
+    #[case(4294967291)]
+    #[case(1_000_000_007)]
+    #[case(u64::MAX - 58)]
+    fn is_prime_number(#[case] n: u64) {
+        assert!(is_prime(n));
+    }
+
+    #[rstest]
+    #[case(0)]
+    #[case(1)]
+    #[case(169)]
+    #[case(561)]
+    #[case(29341)]
+    #[case(3215031751)]
+    #[case(1000003 * 1000033)]
+    #[case(u64::MAX)]
+    fn is_not_prime_number(#[case] n: u64) {
+        assert!(!is_prime(n));
+    }
+
+    #[proptest]
+    fn is_prime_like_trial_division(n: u32) {
+        let n = u64::from(n);
+        let expected = n >= 2 && (2..n).take_while(|d| d * d <= n).all(|d| n % d != 0);
+        assert_eq!(is_prime(n), expected);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_is_probably_composite_fast() {
+        let n: u64 = kani::any();
+        if let Some(prime) = is_probably_composite_fast(n) {
+            assert!(n % prime == 0 && n > prime);
+        }
+    }
+}
@@ -0,0 +1,252 @@
+//! Divisibility checks for numbers written in any base.
+//!
+//! The rules for three (3), nine (9), eleven (11) and ten (10) are not special
+//! to ten, but to the base numbers are written in. In base `b`, the digit sum
+//! decides divisibility by `b - 1` and its factors, the alternating digit sum
+//! decides `b + 1` and its factors, and the last digit decides the factors of
+//! `b`. In hexadecimal, for example, the digit sum decides fifteen (15), five
+//! (5) and three (3), and the alternating digit sum decides seventeen (17).
+
+use crate::utils::{digit_sum_in, last_digit_in, stable_alternating_digit_sum_in};
+
+/// Determines if the provided number is divisible by the provided divisor,
+/// using the digits of the number in the provided base, or `None` if none of
+/// the rules for the base apply to the divisor.
+///
+/// # Panics
+///
+/// Panics if the radix is less than two (2).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::radix::{divisible_by};
+/// assert_eq!(divisible_by(0xFF, 15, 16), Some(true));
+/// assert_eq!(divisible_by(0x1234, 17, 16), Some(false));
+/// assert_eq!(divisible_by(0x1234, 8, 16), Some(false));
+/// assert_eq!(divisible_by(0x1230, 7, 16), None);
+/// ```
+pub fn divisible_by(n: u64, d: u64, radix: u32) -> Option<bool> {
+    assert!(radix >= 2, "radix must be at least 2, got {radix}");
+
+    let radix = u64::from(radix);
+    match d {
+        0 => Some(false),
+        _ if radix.is_multiple_of(d) => Some(divisible_by_last_digit(n, d, radix)),
+        _ if (radix - 1).is_multiple_of(d) => Some(divisible_by_digit_sum(n, d, radix)),
+        _ if (radix + 1).is_multiple_of(d) => Some(divisible_by_alternating_digit_sum(n, d, radix)),
+        _ => None,
+    }
+}
+
+/// Returns the sum of the digits of the provided number in the provided base.
+///
+/// # Panics
+///
+/// Panics if the radix is less than two (2).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::radix::{digit_sum};
+/// assert_eq!(digit_sum(0xFF, 16), 30);
+/// assert_eq!(digit_sum(6468, 10), 24);
+/// ```
+pub fn digit_sum(n: u64, radix: u32) -> u64 {
+    assert!(radix >= 2, "radix must be at least 2, got {radix}");
+    digit_sum_in(n.into(), radix)
+}
+
+/// Returns the alternating sum of the digits of the provided number in the
+/// provided base, starting with the last digit.
+///
+/// # Panics
+///
+/// Panics if the radix is less than two (2).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::radix::{alternating_digit_sum};
+/// assert_eq!(alternating_digit_sum(0x1234, 16), 2);
+/// assert_eq!(alternating_digit_sum(918082, 10), -22);
+/// ```
+pub fn alternating_digit_sum(n: u64, radix: u32) -> i64 {
+    assert!(radix >= 2, "radix must be at least 2, got {radix}");
+    stable_alternating_digit_sum_in(n.into(), radix)
+}
+
+/// Returns the last digit of the provided number in the provided base.
+///
+/// # Panics
+///
+/// Panics if the radix is less than two (2).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::radix::{last_digit};
+/// assert_eq!(last_digit(0x1234, 16), 4);
+/// assert_eq!(last_digit(35, 36), 35);
+/// ```
+pub fn last_digit(n: u64, radix: u32) -> u64 {
+    assert!(radix >= 2, "radix must be at least 2, got {radix}");
+    last_digit_in(n.into(), radix)
+}
+
+fn divisible_by_last_digit(n: u64, d: u64, radix: u64) -> bool {
+    let last = last_digit_in(n.into(), radix as u32);
+    crate::divisible_by(last, d)
+}
+
+fn divisible_by_digit_sum(n: u64, d: u64, radix: u64) -> bool {
+    let mut n = n;
+    while n >= radix {
+        n = digit_sum_in(n.into(), radix as u32);
+    }
+
+    crate::divisible_by(n, d)
+}
+
+fn divisible_by_alternating_digit_sum(n: u64, d: u64, radix: u64) -> bool {
+    let mut n = n;
+    while n >= radix {
+        n = stable_alternating_digit_sum_in(n.into(), radix as u32).unsigned_abs();
+    }
+
+    crate::divisible_by(n, d)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(0xFE, 15, 16)]
+    #[case(0x1234, 17, 16)]
+    #[case(0x1234, 8, 16)]
+    #[case(35 * 36 + 1, 5, 36)]
+    #[case(36 * 36, 37, 36)]
+    #[case(1, 2, 2)]
+    #[case(u64::MAX, 16, 16)]
+    #[case(42, 0, 16)]
+    fn is_not_divisible_by_in_radix(#[case] n: u64, #[case] d: u64, #[case] radix: u32) {
+        assert_eq!(divisible_by(n, d, radix), Some(false));
+    }
+
+    #[rstest]
+    #[case(0, 17, 16)]
+    #[case(0xFF, 15, 16)]
+    #[case(0x1122, 17, 16)]
+    #[case(0x1230, 16, 16)]
+    #[case(35 * 1234, 7, 36)]
+    #[case(37 * 1234, 37, 36)]
+    #[case(36 * 36, 18, 36)]
+    #[case(u64::MAX, 3, 2)]
+    #[case(u64::MAX, 17, 16)]
+    #[case(42, 1, 16)]
+    fn is_divisible_by_in_radix(#[case] n: u64, #[case] d: u64, #[case] radix: u32) {
+        assert_eq!(divisible_by(n, d, radix), Some(true));
+    }
+
+    #[rstest]
+    #[case(7, 16)]
+    #[case(11, 16)]
+    #[case(10, 36)]
+    #[case(7, 10)]
+    fn is_not_decided_in_radix(#[case] d: u64, #[case] radix: u32) {
+        assert_eq!(divisible_by(42, d, radix), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be at least 2, got 1")]
+    fn is_not_decided_in_radix_1() {
+        divisible_by(42, 1, 1);
+    }
+
+    #[rstest]
+    #[case(0, 16, 0)]
+    #[case(0xFF, 16, 30)]
+    #[case(u64::MAX, 2, 64)]
+    #[case(u64::MAX, 36, 155)]
+    fn digit_sum_in_radix_returns_the_digit_sum(
+        #[case] n: u64,
+        #[case] radix: u32,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(digit_sum(n, radix), expected);
+    }
+
+    #[rstest]
+    #[case(0, 16, 0)]
+    #[case(0x1234, 16, 2)]
+    #[case(0b1011, 2, -1)]
+    #[case(u64::MAX, 16, 0)]
+    fn alternating_digit_sum_in_radix_returns_the_alternating_digit_sum(
+        #[case] n: u64,
+        #[case] radix: u32,
+        #[case] expected: i64,
+    ) {
+        assert_eq!(alternating_digit_sum(n, radix), expected);
+    }
+
+    #[rstest]
+    #[case(0, 16, 0)]
+    #[case(0x1234, 16, 4)]
+    #[case(u64::MAX, 2, 1)]
+    #[case(u64::MAX, u32::MAX, 0)]
+    fn last_digit_in_radix_returns_the_last_digit(
+        #[case] n: u64,
+        #[case] radix: u32,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(last_digit(n, radix), expected);
+    }
+
+    #[proptest]
+    fn are_divisible_by_in_radix_like_the_remainder(n: u64, d: u8, radix: u8) {
+        let radix = u32::from(radix % 35) + 2;
+        if let Some(result) = divisible_by(n, d.into(), radix) {
+            assert_eq!(result, n.checked_rem(d.into()) == Some(0));
+        }
+    }
+
+    #[proptest]
+    fn are_decided_for_the_neighbours_of_the_radix(base: u32, radix: u16) {
+        let radix = u32::from(radix) + 2;
+        for d in [radix - 1, radix, radix + 1] {
+            let n = u64::from(d) * u64::from(base);
+            assert_eq!(divisible_by(n, d.into(), radix), Some(true));
+            assert_eq!(divisible_by(n + 1, d.into(), radix), Some(d == 1));
+        }
+    }
+
+    #[proptest]
+    fn are_the_digits_of_the_std_formatting_in_hexadecimal(n: u64) {
+        let expected: u64 = format!("{n:x}")
+            .chars()
+            .map(|char| u64::from(char.to_digit(16).unwrap()))
+            .sum();
+        assert_eq!(digit_sum(n, 16), expected);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(17)]
+    pub fn check_divisible_by_in_radix() {
+        let n: u16 = kani::any();
+        let d: u8 = kani::any();
+        let radix: u32 = kani::any();
+        kani::assume(radix >= 2 && radix <= 16);
+        if let Some(result) = divisible_by(n.into(), d.into(), radix) {
+            assert_eq!(result, n.checked_rem(d.into()) == Some(0));
+        }
+    }
+}
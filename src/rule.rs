@@ -0,0 +1,364 @@
+//! Divisibility rules as values that can be listed, described and replaced.
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::collections::BTreeMap;
+use core::fmt;
+
+use crate::{
+    divisible_by_0, divisible_by_1, divisible_by_10, divisible_by_11, divisible_by_12,
+    divisible_by_2, divisible_by_3, divisible_by_4, divisible_by_5, divisible_by_6, divisible_by_7,
+    divisible_by_8, divisible_by_9,
+};
+
+/// A rule that determines if a number is divisible by a specific divisor.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{Rule};
+/// struct Thirteen;
+///
+/// impl Rule for Thirteen {
+///     fn name(&self) -> &str {
+///         "thirteen"
+///     }
+///
+///     fn divisor(&self) -> u64 {
+///         13
+///     }
+///
+///     fn description(&self) -> &str {
+///         "The number is divisible by 13 according to the remainder."
+///     }
+///
+///     fn apply(&self, n: u64) -> bool {
+///         n % 13 == 0
+///     }
+/// }
+///
+/// assert!(Thirteen.apply(39));
+/// ```
+pub trait Rule {
+    /// Returns the name of the rule.
+    fn name(&self) -> &str;
+
+    /// Returns the divisor the rule is for.
+    fn divisor(&self) -> u64;
+
+    /// Returns a human-readable description of how the rule works.
+    fn description(&self) -> &str;
+
+    /// Determines if the provided number is divisible by the divisor.
+    fn apply(&self, n: u64) -> bool;
+}
+
+/// One of the rules for the numbers 0 through 12 provided by this crate, e.g.
+/// [`divisible_by_7`].
+#[derive(Clone, Copy)]
+pub struct BuiltinRule {
+    name: &'static str,
+    divisor: u64,
+    description: &'static str,
+    rule: fn(u64) -> bool,
+}
+
+impl Rule for BuiltinRule {
+    fn name(&self) -> &str {
+        self.name
+    }
+
+    fn divisor(&self) -> u64 {
+        self.divisor
+    }
+
+    fn description(&self) -> &str {
+        self.description
+    }
+
+    fn apply(&self, n: u64) -> bool {
+        (self.rule)(n)
+    }
+}
+
+impl fmt::Debug for BuiltinRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BuiltinRule")
+            .field("name", &self.name)
+            .field("divisor", &self.divisor)
+            .finish()
+    }
+}
+
+/// The rules for the numbers 0 through 12 provided by this crate, ordered by
+/// their divisor.
+pub const BUILTIN_RULES: [BuiltinRule; 13] = [
+    BuiltinRule {
+        name: "divisible_by_0",
+        divisor: 0,
+        description: "No number is considered divisible by zero.",
+        rule: divisible_by_0,
+    },
+    BuiltinRule {
+        name: "divisible_by_1",
+        divisor: 1,
+        description: "Every number is divisible by one.",
+        rule: divisible_by_1,
+    },
+    BuiltinRule {
+        name: "divisible_by_2",
+        divisor: 2,
+        description: "The last digit is 0, 2, 4, 6 or 8.",
+        rule: divisible_by_2,
+    },
+    BuiltinRule {
+        name: "divisible_by_3",
+        divisor: 3,
+        description: "The digit sum, repeated until a single digit remains, is 0, 3, 6 or 9.",
+        rule: divisible_by_3,
+    },
+    BuiltinRule {
+        name: "divisible_by_4",
+        divisor: 4,
+        description: "The number is divisible by 2 and so is half of it.",
+        rule: divisible_by_4,
+    },
+    BuiltinRule {
+        name: "divisible_by_5",
+        divisor: 5,
+        description: "The last digit is 0 or 5.",
+        rule: divisible_by_5,
+    },
+    BuiltinRule {
+        name: "divisible_by_6",
+        divisor: 6,
+        description: "The number is divisible by both 2 and 3.",
+        rule: divisible_by_6,
+    },
+    BuiltinRule {
+        name: "divisible_by_7",
+        divisor: 7,
+        description: "Adding five times the last digit to the rest of the number, repeated \
+                      while the number is greater than 50, gives a multiple of 7.",
+        rule: divisible_by_7,
+    },
+    BuiltinRule {
+        name: "divisible_by_8",
+        divisor: 8,
+        description: "The number is divisible by 2 and half of it is divisible by 4.",
+        rule: divisible_by_8,
+    },
+    BuiltinRule {
+        name: "divisible_by_9",
+        divisor: 9,
+        description: "The digit sum, repeated until a single digit remains, is 0 or 9.",
+        rule: divisible_by_9,
+    },
+    BuiltinRule {
+        name: "divisible_by_10",
+        divisor: 10,
+        description: "The last digit is 0.",
+        rule: divisible_by_10,
+    },
+    BuiltinRule {
+        name: "divisible_by_11",
+        divisor: 11,
+        description: "The alternating digit sum, repeated until less than 11 remains, is 0.",
+        rule: divisible_by_11,
+    },
+    BuiltinRule {
+        name: "divisible_by_12",
+        divisor: 12,
+        description: "The number is divisible by both 3 and 4.",
+        rule: divisible_by_12,
+    },
+];
+
+/// A collection of rules with at most one rule per divisor.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{RuleRegistry};
+/// let registry = RuleRegistry::with_builtin_rules();
+/// let rule = registry.get(7).unwrap();
+/// assert_eq!(rule.name(), "divisible_by_7");
+/// assert!(rule.apply(21));
+/// assert_eq!(registry.iter().count(), 13);
+/// ```
+#[cfg(feature = "alloc")]
+#[derive(Default)]
+pub struct RuleRegistry {
+    rules: BTreeMap<u64, Box<dyn Rule>>,
+}
+
+#[cfg(feature = "alloc")]
+impl RuleRegistry {
+    /// Creates a registry without any rules.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Creates a registry with the rules for the numbers 0 through 12 provided
+    /// by this crate, see [`BUILTIN_RULES`].
+    pub fn with_builtin_rules() -> Self {
+        let mut registry = Self::new();
+        for rule in BUILTIN_RULES {
+            registry.register(rule);
+        }
+
+        registry
+    }
+
+    /// Adds a rule to the registry, returning the rule it replaces for the
+    /// same divisor if any.
+    pub fn register<R: Rule + 'static>(&mut self, rule: R) -> Option<Box<dyn Rule>> {
+        self.rules.insert(rule.divisor(), Box::new(rule))
+    }
+
+    /// Removes the rule for the provided divisor from the registry.
+    pub fn unregister(&mut self, d: u64) -> Option<Box<dyn Rule>> {
+        self.rules.remove(&d)
+    }
+
+    /// Returns the rule for the provided divisor.
+    pub fn get(&self, d: u64) -> Option<&dyn Rule> {
+        self.rules.get(&d).map(|rule| rule.as_ref())
+    }
+
+    /// Determines if the provided number is divisible by the provided divisor,
+    /// or `None` if there is no rule for the divisor.
+    pub fn apply(&self, n: u64, d: u64) -> Option<bool> {
+        self.get(d).map(|rule| rule.apply(n))
+    }
+
+    /// Returns an iterator over all rules, ordered by their divisor.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Rule> {
+        self.rules.values().map(|rule| rule.as_ref())
+    }
+
+    /// Returns the number of rules in the registry.
+    pub fn len(&self) -> usize {
+        self.rules.len()
+    }
+
+    /// Returns `true` if the registry has no rules.
+    pub fn is_empty(&self) -> bool {
+        self.rules.is_empty()
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl fmt::Debug for RuleRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(self.rules.iter().map(|(d, rule)| (d, rule.name())))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    struct Remainder(u64);
+
+    impl Rule for Remainder {
+        fn name(&self) -> &str {
+            "remainder"
+        }
+
+        fn divisor(&self) -> u64 {
+            self.0
+        }
+
+        fn description(&self) -> &str {
+            "The remainder is 0."
+        }
+
+        fn apply(&self, n: u64) -> bool {
+            n.is_multiple_of(self.0)
+        }
+    }
+
+    #[test]
+    fn is_empty_when_new() {
+        let registry = RuleRegistry::new();
+        assert!(registry.is_empty());
+        assert!(registry.get(7).is_none());
+        assert_eq!(registry.apply(21, 7), None);
+    }
+
+    #[test]
+    fn has_the_builtin_rules_in_order() {
+        let registry = RuleRegistry::with_builtin_rules();
+        let divisors: Vec<u64> = registry.iter().map(|rule| rule.divisor()).collect();
+        assert_eq!(divisors, (0..=12).collect::<Vec<u64>>());
+        assert_eq!(registry.len(), 13);
+    }
+
+    #[rstest]
+    #[case(13)]
+    #[case(100)]
+    #[case(u64::MAX)]
+    fn has_no_builtin_rule_beyond_12(#[case] d: u64) {
+        let registry = RuleRegistry::with_builtin_rules();
+        assert!(registry.get(d).is_none());
+    }
+
+    #[test]
+    fn is_extended_with_custom_rules() {
+        let mut registry = RuleRegistry::with_builtin_rules();
+        assert!(registry.register(Remainder(13)).is_none());
+        assert_eq!(registry.apply(39, 13), Some(true));
+        assert_eq!(registry.iter().last().map(|rule| rule.divisor()), Some(13));
+    }
+
+    #[test]
+    fn is_swapped_with_custom_rules() {
+        let mut registry = RuleRegistry::with_builtin_rules();
+        let replaced = registry.register(Remainder(7)).unwrap();
+        assert_eq!(replaced.name(), "divisible_by_7");
+        assert_eq!(registry.get(7).unwrap().name(), "remainder");
+        assert_eq!(registry.len(), 13);
+
+        let removed = registry.unregister(7).unwrap();
+        assert_eq!(removed.name(), "remainder");
+        assert!(registry.get(7).is_none());
+    }
+
+    #[test]
+    fn has_descriptions_for_every_builtin_rule() {
+        for rule in BUILTIN_RULES {
+            assert_eq!(rule.name(), format!("divisible_by_{}", rule.divisor()));
+            assert!(rule.description().ends_with('.'));
+        }
+    }
+
+    #[proptest]
+    fn agrees_with_the_rules(n: u64) {
+        let registry = RuleRegistry::with_builtin_rules();
+        for rule in registry.iter() {
+            assert_eq!(rule.apply(n), crate::divisible_by(n, rule.divisor()));
+        }
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_builtin_rules() {
+        let n: u64 = kani::any();
+        let i: usize = kani::any();
+        kani::assume(i < BUILTIN_RULES.len());
+        let rule = BUILTIN_RULES[i];
+        assert_eq!(rule.apply(n), crate::divisible_by(n, rule.divisor()));
+    }
+}
@@ -0,0 +1,402 @@
+//! Divisibility checks for signed numbers.
+//!
+//! A negative number is divisible by a divisor exactly when its magnitude is,
+//! so -21 is divisible by seven (7). Zero (0) is divisible by every non-zero
+//! divisor. The magnitude is taken with [`i64::unsigned_abs`], which is exact
+//! for [`i64::MIN`] where negating the number would overflow.
+
+/// Determines if the provided signed number is divisible by zero (0).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_0};
+/// let any_number = -42;
+/// assert!(!divisible_by_0(any_number));
+/// ```
+pub const fn divisible_by_0(n: i64) -> bool {
+    crate::divisible_by_0(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by one (1).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_1};
+/// let any_number = -42;
+/// assert!(divisible_by_1(any_number));
+/// ```
+pub const fn divisible_by_1(n: i64) -> bool {
+    crate::divisible_by_1(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by two (2).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_2};
+/// assert!(divisible_by_2(-42));
+/// assert!(!divisible_by_2(-9));
+/// ```
+pub const fn divisible_by_2(n: i64) -> bool {
+    crate::divisible_by_2(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by three (3).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_3};
+/// assert!(divisible_by_3(-9));
+/// assert!(!divisible_by_3(-8));
+/// ```
+pub const fn divisible_by_3(n: i64) -> bool {
+    crate::divisible_by_3(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by four (4).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_4};
+/// assert!(divisible_by_4(-8));
+/// assert!(!divisible_by_4(-15));
+/// ```
+pub const fn divisible_by_4(n: i64) -> bool {
+    crate::divisible_by_4(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by five (5).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_5};
+/// assert!(divisible_by_5(-15));
+/// assert!(!divisible_by_5(-12));
+/// ```
+pub const fn divisible_by_5(n: i64) -> bool {
+    crate::divisible_by_5(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by six (6).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_6};
+/// assert!(divisible_by_6(-12));
+/// assert!(!divisible_by_6(-21));
+/// ```
+pub const fn divisible_by_6(n: i64) -> bool {
+    crate::divisible_by_6(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by seven (7).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_7};
+/// assert!(divisible_by_7(-21));
+/// assert!(!divisible_by_7(-16));
+/// ```
+pub const fn divisible_by_7(n: i64) -> bool {
+    crate::divisible_by_7(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by eight (8).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_8};
+/// assert!(divisible_by_8(-16));
+/// assert!(!divisible_by_8(-18));
+/// ```
+pub const fn divisible_by_8(n: i64) -> bool {
+    crate::divisible_by_8(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by nine (9).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_9};
+/// assert!(divisible_by_9(-18));
+/// assert!(!divisible_by_9(-20));
+/// ```
+pub const fn divisible_by_9(n: i64) -> bool {
+    crate::divisible_by_9(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by ten (10).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_10};
+/// assert!(divisible_by_10(-20));
+/// assert!(!divisible_by_10(-22));
+/// ```
+pub const fn divisible_by_10(n: i64) -> bool {
+    crate::divisible_by_10(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by eleven (11).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_11};
+/// assert!(divisible_by_11(-22));
+/// assert!(!divisible_by_11(-36));
+/// ```
+pub const fn divisible_by_11(n: i64) -> bool {
+    crate::divisible_by_11(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by twelve (12).
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by_12};
+/// assert!(divisible_by_12(-36));
+/// assert!(!divisible_by_12(-42));
+/// ```
+pub const fn divisible_by_12(n: i64) -> bool {
+    crate::divisible_by_12(n.unsigned_abs())
+}
+
+/// Determines if the provided signed number is divisible by the provided
+/// signed divisor.
+///
+/// Both the number and the divisor are taken by magnitude, so the check uses
+/// the same rules as [`crate::divisible_by`].
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::signed::{divisible_by};
+/// assert!(divisible_by(-42, 7));
+/// assert!(divisible_by(42, -7));
+/// assert!(!divisible_by(-1310, -17));
+/// ```
+pub const fn divisible_by(n: i64, d: i64) -> bool {
+    crate::divisible_by(n.unsigned_abs(), d.unsigned_abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    #[rstest]
+    #[case(i64::MIN)]
+    #[case(-1)]
+    #[case(0)]
+    #[case(i64::MAX)]
+    fn is_not_divisible_by_0(#[case] n: i64) {
+        let result = divisible_by_0(n);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(i64::MIN)]
+    #[case(-1)]
+    #[case(0)]
+    #[case(i64::MAX)]
+    fn is_divisible_by_1(#[case] n: i64) {
+        let result = divisible_by_1(n);
+        assert!(result);
+    }
+
+    #[rstest]
+    #[case(-1, 2)]
+    #[case(-8, 3)]
+    #[case(-6, 4)]
+    #[case(-12, 5)]
+    #[case(-21, 6)]
+    #[case(-16, 7)]
+    #[case(-18, 8)]
+    #[case(-20, 9)]
+    #[case(-22, 10)]
+    #[case(-36, 11)]
+    #[case(-42, 12)]
+    #[case(i64::MIN, 3)]
+    #[case(i64::MIN, 5)]
+    #[case(i64::MIN, 6)]
+    #[case(i64::MIN, 7)]
+    #[case(i64::MIN, 9)]
+    #[case(i64::MIN, 10)]
+    #[case(i64::MIN, 11)]
+    #[case(i64::MIN, 12)]
+    fn is_not_divisible_by(#[case] n: i64, #[case] d: i64) {
+        let result = divisible_by(n, d);
+        assert!(!result);
+    }
+
+    #[rstest]
+    #[case(-2, 2)]
+    #[case(-3, 3)]
+    #[case(-4, 4)]
+    #[case(-5, 5)]
+    #[case(-6, 6)]
+    #[case(-21, 7)]
+    #[case(-8, 8)]
+    #[case(-9, 9)]
+    #[case(-10, 10)]
+    #[case(-11, 11)]
+    #[case(-12, 12)]
+    #[case(i64::MIN, 2)]
+    #[case(i64::MIN, 4)]
+    #[case(i64::MIN, 8)]
+    #[case(i64::MIN, i64::MIN)]
+    #[case(0, i64::MIN)]
+    fn is_divisible_by(#[case] n: i64, #[case] d: i64) {
+        let result = divisible_by(n, d);
+        assert!(result);
+    }
+
+    #[proptest]
+    fn zero_is_divisible_by_everything_non_zero(d: i64) {
+        assert_eq!(divisible_by(0, d), d != 0);
+    }
+
+    #[proptest]
+    fn are_divisible_by_n_and_minus_n_alike(n: i64) {
+        let m = n.saturating_neg();
+        if n != i64::MIN {
+            assert_eq!(divisible_by_2(n), divisible_by_2(m));
+            assert_eq!(divisible_by_3(n), divisible_by_3(m));
+            assert_eq!(divisible_by_4(n), divisible_by_4(m));
+            assert_eq!(divisible_by_5(n), divisible_by_5(m));
+            assert_eq!(divisible_by_6(n), divisible_by_6(m));
+            assert_eq!(divisible_by_7(n), divisible_by_7(m));
+            assert_eq!(divisible_by_8(n), divisible_by_8(m));
+            assert_eq!(divisible_by_9(n), divisible_by_9(m));
+            assert_eq!(divisible_by_10(n), divisible_by_10(m));
+            assert_eq!(divisible_by_11(n), divisible_by_11(m));
+            assert_eq!(divisible_by_12(n), divisible_by_12(m));
+        }
+    }
+
+    #[proptest]
+    fn are_divisible_by_the_remainder_definition(n: i64) {
+        assert_eq!(divisible_by_2(n), n % 2 == 0);
+        assert_eq!(divisible_by_3(n), n % 3 == 0);
+        assert_eq!(divisible_by_4(n), n % 4 == 0);
+        assert_eq!(divisible_by_5(n), n % 5 == 0);
+        assert_eq!(divisible_by_6(n), n % 6 == 0);
+        assert_eq!(divisible_by_7(n), n % 7 == 0);
+        assert_eq!(divisible_by_8(n), n % 8 == 0);
+        assert_eq!(divisible_by_9(n), n % 9 == 0);
+        assert_eq!(divisible_by_10(n), n % 10 == 0);
+        assert_eq!(divisible_by_11(n), n % 11 == 0);
+        assert_eq!(divisible_by_12(n), n % 12 == 0);
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_0() {
+        let n: i64 = kani::any();
+        assert!(!divisible_by_0(n));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_1() {
+        let n: i64 = kani::any();
+        assert!(divisible_by_1(n));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_2() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_2(n), n % 2 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_3() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_3(n), n % 3 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_4() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_4(n), n % 4 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_5() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_5(n), n % 5 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_6() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_6(n), n % 6 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_7() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_7(n), n % 7 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_8() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_8(n), n % 8 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_9() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_9(n), n % 9 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_10() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_10(n), n % 10 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_11() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_11(n), n % 11 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(1)]
+    pub fn check_divisible_by_12() {
+        let n: i64 = kani::any();
+        assert_eq!(divisible_by_12(n), n % 12 == 0);
+    }
+}
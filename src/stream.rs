@@ -0,0 +1,374 @@
+//! Divisibility checks for numbers that are too long to keep in memory.
+
+#[cfg(feature = "std")]
+use std::format;
+#[cfg(feature = "std")]
+use std::io::{self, Read};
+
+use crate::utils::digit_sum;
+use crate::{
+    divisible_by_0, divisible_by_1, divisible_by_10, divisible_by_11, divisible_by_2,
+    divisible_by_3, divisible_by_4, divisible_by_5, divisible_by_7, divisible_by_8, divisible_by_9,
+    DivisorSet,
+};
+
+/// The number of bytes in the serialized form of a [`DivisibilityState`].
+pub const STATE_SIZE: usize = 13;
+
+/// The divisibility of a number that is provided one decimal digit at a time,
+/// starting with the most significant digit.
+///
+/// Only a few bytes of state are kept regardless of how many digits have been
+/// provided:
+///
+/// - the digit sum, which decides three (3) and nine (9);
+/// - the alternating digit sum, which decides eleven (11);
+/// - the remainder by seven (7), updated as `3 * remainder + digit` because ten
+///   is three more than seven;
+/// - the last three digits, which decide two (2), four (4), five (5), eight (8)
+///   and ten (10).
+///
+/// The state can be saved with [`DivisibilityState::to_bytes`] and restored
+/// with [`DivisibilityState::from_bytes`] to resume later.
+///
+/// # Examples
+///
+/// ```
+/// # use divisible_by::{DivisibilityState};
+/// let state: DivisibilityState = [4, 2].into_iter().collect();
+/// assert_eq!(state.divisible_by(7), Some(true));
+/// assert_eq!(state.divisible_by(8), Some(false));
+/// ```
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, PartialEq)]
+pub struct DivisibilityState {
+    digit_sum: u8,
+    alternating_digit_sum: u8,
+    remainder_by_7: u8,
+    last_digits: u16,
+    len: u64,
+}
+
+impl DivisibilityState {
+    /// Creates the state for a number without any digits, i.e. zero (0).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Appends a digit to the end of the number.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digit is not in the range 0 through 9.
+    pub fn push_digit(&mut self, digit: u8) {
+        assert!(digit < 10, "{digit} is not a decimal digit");
+
+        self.digit_sum += digit;
+        if self.digit_sum >= 10 {
+            self.digit_sum = digit_sum(self.digit_sum.into()) as u8;
+        }
+
+        self.alternating_digit_sum = 11 + digit - self.alternating_digit_sum;
+        if self.alternating_digit_sum >= 11 {
+            self.alternating_digit_sum -= 11;
+        }
+
+        self.remainder_by_7 = 3 * self.remainder_by_7 + digit;
+        while self.remainder_by_7 >= 7 {
+            self.remainder_by_7 -= 7;
+        }
+
+        self.last_digits = (self.last_digits % 100) * 10 + u16::from(digit);
+        self.len += 1;
+    }
+
+    /// Appends the ASCII digits read from the provided reader to the number,
+    /// returning how many digits were read. Line breaks are skipped.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if reading fails or if a byte other than an ASCII digit
+    /// or line break is read, in which case the digits read before the invalid
+    /// byte are kept.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use divisible_by::{DivisibilityState};
+    /// let mut state = DivisibilityState::new();
+    /// let count = state.read_digits("1001\n1001\n".as_bytes()).unwrap();
+    /// assert_eq!(count, 8);
+    /// assert_eq!(state.divisible_by(11), Some(true));
+    /// ```
+    #[cfg(feature = "std")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+    pub fn read_digits<R: Read>(&mut self, mut reader: R) -> io::Result<u64> {
+        let mut buffer = [0; 8 * 1024];
+        let mut count = 0;
+        loop {
+            let read = match reader.read(&mut buffer) {
+                Ok(0) => return Ok(count),
+                Ok(read) => read,
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+                Err(error) => return Err(error),
+            };
+
+            for byte in &buffer[..read] {
+                match byte {
+                    b'0'..=b'9' => {
+                        self.push_digit(byte - b'0');
+                        count += 1;
+                    }
+                    b'\n' | b'\r' => {}
+                    _ => {
+                        return Err(io::Error::new(
+                            io::ErrorKind::InvalidData,
+                            format!("{:?} is not a decimal digit", char::from(*byte)),
+                        ))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns the number of digits provided so far.
+    pub fn len(&self) -> u64 {
+        self.len
+    }
+
+    /// Returns `true` if no digits have been provided so far.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Determines if the number provided so far is divisible by the provided
+    /// divisor, or `None` if the divisor is larger than twelve (12).
+    pub fn divisible_by(&self, d: u64) -> Option<bool> {
+        let digit_sum = self.digit_sum.into();
+        let last_digits = self.last_digits.into();
+        let result = match d {
+            0 => divisible_by_0(last_digits),
+            1 => divisible_by_1(last_digits),
+            2 => divisible_by_2(last_digits),
+            3 => divisible_by_3(digit_sum),
+            4 => divisible_by_4(last_digits),
+            5 => divisible_by_5(last_digits),
+            6 => divisible_by_2(last_digits) && divisible_by_3(digit_sum),
+            7 => divisible_by_7(self.remainder_by_7.into()),
+            8 => divisible_by_8(last_digits),
+            9 => divisible_by_9(digit_sum),
+            10 => divisible_by_10(last_digits),
+            11 => divisible_by_11(self.alternating_digit_sum.into()),
+            12 => divisible_by_3(digit_sum) && divisible_by_4(last_digits),
+            _ => return None,
+        };
+
+        Some(result)
+    }
+
+    /// Returns the set of numbers from zero (0) through twelve (12) that divide
+    /// the number provided so far, see [`divisors_up_to_12`].
+    ///
+    /// [`divisors_up_to_12`]: crate::divisors_up_to_12
+    pub fn divisors(&self) -> DivisorSet {
+        (0..=12)
+            .filter(|d| self.divisible_by(*d) == Some(true))
+            .collect()
+    }
+
+    /// Serializes the state so it can be restored later.
+    pub fn to_bytes(&self) -> [u8; STATE_SIZE] {
+        let mut bytes = [0; STATE_SIZE];
+        bytes[0] = self.digit_sum;
+        bytes[1] = self.alternating_digit_sum;
+        bytes[2] = self.remainder_by_7;
+        bytes[3..5].copy_from_slice(&self.last_digits.to_le_bytes());
+        bytes[5..].copy_from_slice(&self.len.to_le_bytes());
+        bytes
+    }
+
+    /// Restores a state serialized with [`DivisibilityState::to_bytes`], or
+    /// `None` if the bytes do not describe a valid state.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use divisible_by::{DivisibilityState};
+    /// let mut state = DivisibilityState::new();
+    /// state.push_digit(4);
+    ///
+    /// let mut resumed = DivisibilityState::from_bytes(state.to_bytes()).unwrap();
+    /// resumed.push_digit(2);
+    /// assert_eq!(resumed.divisible_by(6), Some(true));
+    /// ```
+    pub fn from_bytes(bytes: [u8; STATE_SIZE]) -> Option<Self> {
+        let mut last_digits = [0; 2];
+        last_digits.copy_from_slice(&bytes[3..5]);
+        let mut len = [0; 8];
+        len.copy_from_slice(&bytes[5..]);
+
+        let state = Self {
+            digit_sum: bytes[0],
+            alternating_digit_sum: bytes[1],
+            remainder_by_7: bytes[2],
+            last_digits: u16::from_le_bytes(last_digits),
+            len: u64::from_le_bytes(len),
+        };
+
+        let valid = state.digit_sum < 10
+            && state.alternating_digit_sum < 11
+            && state.remainder_by_7 < 7
+            && state.last_digits < 1000;
+        valid.then_some(state)
+    }
+}
+
+impl Extend<u8> for DivisibilityState {
+    fn extend<I: IntoIterator<Item = u8>>(&mut self, digits: I) {
+        for digit in digits {
+            self.push_digit(digit);
+        }
+    }
+}
+
+impl FromIterator<u8> for DivisibilityState {
+    fn from_iter<I: IntoIterator<Item = u8>>(digits: I) -> Self {
+        let mut state = Self::new();
+        state.extend(digits);
+        state
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use proptest_attr_macro::proptest;
+    use rstest::rstest;
+
+    fn state_of(n: &str) -> DivisibilityState {
+        n.bytes().map(|digit| digit - b'0').collect()
+    }
+
+    #[test]
+    fn empty_is_divisible_by_everything_non_zero() {
+        let state = DivisibilityState::new();
+        assert!(state.is_empty());
+        assert_eq!(state.divisible_by(0), Some(false));
+        for d in 1..=12 {
+            assert_eq!(state.divisible_by(d), Some(true));
+        }
+    }
+
+    #[rstest]
+    #[case(13)]
+    #[case(100)]
+    #[case(u64::MAX)]
+    fn is_not_decided_beyond_12(#[case] d: u64) {
+        let state = state_of("12");
+        assert_eq!(state.divisible_by(d), None);
+    }
+
+    #[rstest]
+    #[case("1a", 1)]
+    #[case("12 3", 2)]
+    #[case("-1", 0)]
+    fn is_not_read_from_invalid_digits(#[case] n: &str, #[case] len: u64) {
+        let mut state = DivisibilityState::new();
+        let result = state.read_digits(n.as_bytes());
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        assert_eq!(state.len(), len);
+    }
+
+    #[test]
+    #[should_panic]
+    fn is_not_pushed_a_non_digit() {
+        DivisibilityState::new().push_digit(10);
+    }
+
+    #[rstest]
+    #[case([10, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])]
+    #[case([0, 11, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])]
+    #[case([0, 0, 7, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0])]
+    #[case([0, 0, 0, 0xe8, 0x03, 0, 0, 0, 0, 0, 0, 0, 0])]
+    fn is_not_restored_from_invalid_bytes(#[case] bytes: [u8; STATE_SIZE]) {
+        assert_eq!(DivisibilityState::from_bytes(bytes), None);
+    }
+
+    #[proptest]
+    fn agrees_with_the_rules(n: u64) {
+        let state = state_of(&n.to_string());
+        assert_eq!(state.divisible_by(0), Some(divisible_by_0(n)));
+        assert_eq!(state.divisible_by(1), Some(divisible_by_1(n)));
+        assert_eq!(state.divisible_by(2), Some(divisible_by_2(n)));
+        assert_eq!(state.divisible_by(3), Some(divisible_by_3(n)));
+        assert_eq!(state.divisible_by(4), Some(divisible_by_4(n)));
+        assert_eq!(state.divisible_by(5), Some(divisible_by_5(n)));
+        assert_eq!(state.divisible_by(6), Some(crate::divisible_by_6(n)));
+        assert_eq!(state.divisible_by(7), Some(divisible_by_7(n)));
+        assert_eq!(state.divisible_by(8), Some(divisible_by_8(n)));
+        assert_eq!(state.divisible_by(9), Some(divisible_by_9(n)));
+        assert_eq!(state.divisible_by(10), Some(divisible_by_10(n)));
+        assert_eq!(state.divisible_by(11), Some(divisible_by_11(n)));
+        assert_eq!(state.divisible_by(12), Some(crate::divisible_by_12(n)));
+    }
+
+    #[proptest]
+    fn agrees_with_the_string_rules(high: u128, low: u128) {
+        let n = format!("{high}{low:039}");
+        let state = state_of(&n);
+        assert_eq!(state.divisible_by(7), crate::divisible_by_7_str(&n).ok());
+        assert_eq!(state.divisible_by(8), crate::divisible_by_8_str(&n).ok());
+        assert_eq!(state.divisible_by(11), crate::divisible_by_11_str(&n).ok());
+        assert_eq!(state.divisible_by(12), crate::divisible_by_12_str(&n).ok());
+    }
+
+    #[proptest]
+    fn has_the_divisors_of_the_number(n: u64) {
+        let state = state_of(&n.to_string());
+        assert_eq!(state.divisors(), crate::divisors_up_to_12(n));
+    }
+
+    #[proptest]
+    fn is_read_like_it_is_pushed(n: u128) {
+        let digits = format!("{n}\r\n{n}\n");
+        let mut state = DivisibilityState::new();
+        let count = state.read_digits(digits.as_bytes()).unwrap();
+        assert_eq!(count, state.len());
+        assert_eq!(state, state_of(&format!("{n}{n}")));
+    }
+
+    #[proptest]
+    fn is_resumed_from_a_checkpoint(high: u64, low: u64) {
+        let checkpoint = state_of(&high.to_string()).to_bytes();
+        let mut state = DivisibilityState::from_bytes(checkpoint).unwrap();
+        state.extend(low.to_string().bytes().map(|digit| digit - b'0'));
+        assert_eq!(state, state_of(&format!("{high}{low}")));
+    }
+}
+
+#[cfg(kani)]
+mod verification {
+    use super::*;
+
+    #[kani::proof]
+    pub fn check_push_digit() {
+        let bytes: [u8; STATE_SIZE] = kani::any();
+        let digit: u8 = kani::any();
+        kani::assume(digit < 10);
+        if let Some(mut state) = DivisibilityState::from_bytes(bytes) {
+            kani::assume(state.len < u64::MAX);
+            state.push_digit(digit);
+            assert!(DivisibilityState::from_bytes(state.to_bytes()).is_some());
+        }
+    }
+
+    #[kani::proof]
+    pub fn check_two_digits() {
+        let n: u8 = kani::any();
+        kani::assume(n < 100);
+        let state: DivisibilityState = [n / 10, n % 10].into_iter().collect();
+        assert_eq!(state.divisible_by(7), Some(n % 7 == 0));
+        assert_eq!(state.divisible_by(11), Some(n % 11 == 0));
+        assert_eq!(state.divisible_by(12), Some(n % 12 == 0));
+    }
+}
@@ -1,29 +1,186 @@
-pub fn alternating_digit_sum(n: u64) -> i64 {
-    n.to_string()
-        .chars()
-        .map(|char| unsafe { i64::from(char.to_digit(10).unwrap_unchecked()) })
-        .enumerate()
-        .fold(0, |acc, (i, digit)| {
-            acc + ((if i % 2 == 0 { 1 } else { -1 }) * digit)
-        })
-}
-
-pub fn digit_sum(n: u64) -> u64 {
-    n.to_string()
-        .chars()
-        .map(|char| unsafe { u64::from(char.to_digit(10).unwrap_unchecked()) })
-        .sum()
-}
-
-pub fn last_digit(n: u64) -> u64 {
-    u64::from(unsafe {
-        n.to_string()
-            .chars()
-            .last()
-            .unwrap_unchecked()
-            .to_digit(10)
-            .unwrap_unchecked()
-    })
+use crate::Digits;
+
+pub const fn alternating_digit_sum(n: u128) -> i64 {
+    let mut digits = Digits::of(n);
+    let (mut sum, mut sign) = (0, 1);
+    while let Some(digit) = digits.next_most_significant() {
+        sum += sign * digit as i64;
+        sign = -sign;
+    }
+
+    sum
+}
+
+pub const fn stable_alternating_digit_sum(n: u128) -> i64 {
+    let mut digits = Digits::of(n);
+    let (mut sum, mut sign) = (0, 1);
+    while let Some(digit) = digits.next_least_significant() {
+        sum += sign * digit as i64;
+        sign = -sign;
+    }
+
+    sum
+}
+
+pub const fn stable_alternating_digit_sum_in(n: u128, radix: u32) -> i64 {
+    let radix = radix as u128;
+    let (mut n, mut sum, mut sign) = (n, 0, 1);
+    loop {
+        sum += sign * (n % radix) as i64;
+        if n < radix {
+            return sum;
+        }
+
+        n /= radix;
+        sign = -sign;
+    }
+}
+
+pub const fn alternating_block_sum(n: u64, k: usize) -> i128 {
+    if k == 0 {
+        return 0;
+    }
+
+    // A u64 has at most 20 digits, so it is a single block from there on.
+    if k >= 20 {
+        return n as i128;
+    }
+
+    let block = 10u64.pow(k as u32);
+    let (mut n, mut sum, mut sign) = (n, 0, 1);
+    loop {
+        sum += sign * (n % block) as i128;
+        if n < block {
+            return sum;
+        }
+
+        n /= block;
+        sign = -sign;
+    }
+}
+
+pub const fn digit_count_in(n: u128, radix: u32) -> u32 {
+    let radix = radix as u128;
+    let (mut n, mut count) = (n, 1);
+    while n >= radix {
+        n /= radix;
+        count += 1;
+    }
+
+    count
+}
+
+pub const fn digit_sum(n: u128) -> u64 {
+    let mut digits = Digits::of(n);
+    let mut sum = 0;
+    while let Some(digit) = digits.next_least_significant() {
+        sum += digit;
+    }
+
+    sum
+}
+
+pub const fn digit_sum_in(n: u128, radix: u32) -> u64 {
+    let radix = radix as u128;
+    let (mut n, mut sum) = (n, 0);
+    loop {
+        sum += (n % radix) as u64;
+        if n < radix {
+            return sum;
+        }
+
+        n /= radix;
+    }
+}
+
+pub const fn last_digit(n: u128) -> u64 {
+    match Digits::of(n).next_least_significant() {
+        Some(digit) => digit,
+        None => 0,
+    }
+}
+
+pub const fn last_digit_in(n: u128, radix: u32) -> u64 {
+    (n % radix as u128) as u64
+}
+
+pub const fn last_digits(n: u64, k: usize) -> u64 {
+    // A u64 has at most 20 digits, so its last 20 digits are all of them.
+    if k >= 20 {
+        return n;
+    }
+
+    n % 10u64.pow(k as u32)
+}
+
+pub const fn long_division_remainder(n: u64, d: u64) -> u64 {
+    let mut power = 1;
+    while power <= n / 10 {
+        power *= 10;
+    }
+
+    let mut remainder = 0;
+    loop {
+        let digit = (n / power) % 10;
+        let mut next = remainder as u128 * 10 + digit as u128;
+        while next >= d as u128 {
+            next -= d as u128;
+        }
+        remainder = next as u64;
+
+        if power == 1 {
+            return remainder;
+        }
+
+        power /= 10;
+    }
+}
+
+pub const fn osculate(n: u64, multiplier: u64) -> u64 {
+    osculate_with_steps(n, multiplier).0
+}
+
+pub const fn osculate_with_steps(n: u64, multiplier: u64) -> (u64, u32) {
+    if n >= 10 * multiplier {
+        let (rest, last) = split_last_digit(n);
+        let next_n = (last * multiplier) + rest;
+        let (result, steps) = osculate_with_steps(next_n, multiplier);
+        (result, steps + 1)
+    } else {
+        (n, 0)
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub const fn osculate_backward(n: u64, multiplier: u64) -> u64 {
+    let threshold = if multiplier == 0 { 10 } else { 10 * multiplier };
+    if n >= threshold {
+        let (rest, last) = split_last_digit(n);
+        let next_n = rest.abs_diff(last * multiplier);
+        osculate_backward(next_n, multiplier)
+    } else {
+        n
+    }
+}
+
+pub const fn split_last_digit(n: u64) -> (u64, u64) {
+    (n / 10, last_digit(n as u128))
+}
+
+#[cfg(feature = "alloc")]
+pub const fn osculator(d: u64) -> u64 {
+    let d = d as u128;
+    let mut k = 1;
+    while k < 10 {
+        let multiple = k * d;
+        if last_digit(multiple) == 9 {
+            return ((multiple + 1) / 10) as u64;
+        }
+
+        k += 1;
+    }
+
+    1
 }
 
 #[cfg(test)]
@@ -38,8 +195,9 @@ mod tests {
     #[case(2, 2)]
     #[case(12, -1)]
     #[case(6468, 0)]
+    #[case(u128::MAX, 35)]
     fn alternating_digit_sum_returns_the_alternating_digit_sum(
-        #[case] n: u64,
+        #[case] n: u128,
         #[case] expected: i64,
     ) {
         assert_eq!(alternating_digit_sum(n), expected);
@@ -47,7 +205,60 @@ mod tests {
 
     #[proptest]
     fn alternating_digit_sum_is_bounded(n: u64) {
-        assert!(alternating_digit_sum(n).unsigned_abs() <= n);
+        assert!(alternating_digit_sum(n.into()).unsigned_abs() <= n);
+    }
+
+    #[rstest]
+    #[case(0, 0)]
+    #[case(2, 2)]
+    #[case(12, 1)]
+    #[case(123, 2)]
+    #[case(6468, 0)]
+    #[case(u128::MAX, 35)]
+    fn stable_alternating_digit_sum_returns_the_alternating_digit_sum(
+        #[case] n: u128,
+        #[case] expected: i64,
+    ) {
+        assert_eq!(stable_alternating_digit_sum(n), expected);
+    }
+
+    #[proptest]
+    fn stable_alternating_digit_sum_starts_with_the_last_digit(n: u64) {
+        let expected = stable_alternating_digit_sum((n / 10).into());
+        assert_eq!(
+            stable_alternating_digit_sum(n.into()),
+            (n % 10) as i64 - expected
+        );
+    }
+
+    #[rstest]
+    #[case(0, 3, 0)]
+    #[case(1001, 3, 0)]
+    #[case(123456, 3, 333)]
+    #[case(6468, 2, 4)]
+    #[case(42, 0, 0)]
+    #[case(u64::MAX, 3, 1016)]
+    #[case(u64::MAX, 20, u64::MAX as i128)]
+    fn alternating_block_sum_returns_the_alternating_block_sum(
+        #[case] n: u64,
+        #[case] k: usize,
+        #[case] expected: i128,
+    ) {
+        assert_eq!(alternating_block_sum(n, k), expected);
+    }
+
+    #[proptest]
+    fn alternating_block_sum_of_single_digits_is_the_alternating_digit_sum(n: u64) {
+        let expected = stable_alternating_digit_sum(n.into());
+        assert_eq!(alternating_block_sum(n, 1), i128::from(expected));
+    }
+
+    #[proptest]
+    fn alternating_block_sum_keeps_the_remainder(n: u64, k: u8) {
+        let k = u32::from(k % 6) + 1;
+        let d = 10_i128.pow(k) + 1;
+        let sum = alternating_block_sum(n, k as usize);
+        assert_eq!(sum.rem_euclid(d), i128::from(n) % d);
     }
 
     #[rstest]
@@ -55,33 +266,204 @@ mod tests {
     #[case(1, 1)]
     #[case(12, 3)]
     #[case(6468, 24)]
-    fn digit_sum_returns_the_digit_sum(#[case] n: u64, #[case] expected: u64) {
+    #[case(u128::MAX, 165)]
+    fn digit_sum_returns_the_digit_sum(#[case] n: u128, #[case] expected: u64) {
         assert_eq!(digit_sum(n), expected);
     }
 
     #[proptest]
     fn digit_sum_is_bounded(n: u64) {
-        assert!(digit_sum(n) <= n);
+        assert!(digit_sum(n.into()) <= n);
+    }
+
+    #[rstest]
+    #[case(0, 10, 1)]
+    #[case(7, 10, 1)]
+    #[case(120, 10, 3)]
+    #[case(6, 2, 3)]
+    #[case(u128::MAX, 10, 39)]
+    fn digit_count_in_returns_the_number_of_digits(
+        #[case] n: u128,
+        #[case] radix: u32,
+        #[case] expected: u32,
+    ) {
+        assert_eq!(digit_count_in(n, radix), expected);
+    }
+
+    #[proptest]
+    fn digit_count_in_is_one_more_than_the_log(n: u64, radix: u8) {
+        let radix = u32::from(radix.max(2));
+        let expected = n.checked_ilog(radix.into()).unwrap_or(0) + 1;
+        assert_eq!(digit_count_in(n.into(), radix), expected);
+    }
+
+    #[proptest]
+    fn long_division_remainder_is_the_remainder(n: u64, d: u64) {
+        let d = d.max(1);
+        assert_eq!(long_division_remainder(n, d), n % d);
     }
 
     #[rstest]
     #[case(0, 0)]
     #[case(11, 1)]
     #[case(123, 3)]
-    fn last_digit_returns_the_last_digit(#[case] n: u64, #[case] digit: u64) {
+    #[case(u128::MAX, 5)]
+    fn last_digit_returns_the_last_digit(#[case] n: u128, #[case] digit: u64) {
         assert_eq!(last_digit(n), digit);
     }
 
     #[proptest]
-    fn last_digit_returns_a_single_digit(n: u64) {
+    fn last_digit_returns_a_single_digit(n: u128) {
         assert!(last_digit(n) < 10);
     }
 
     #[proptest]
-    fn last_digit_returns_something_n_ends_with(n: u64) {
+    fn last_digit_returns_something_n_ends_with(n: u128) {
         let result = last_digit(n).to_string();
         assert!(n.to_string().ends_with(&result[..]));
     }
+
+    #[rstest]
+    #[case(0, 2, 0)]
+    #[case(7, 2, 7)]
+    #[case(123, 0, 0)]
+    #[case(123, 2, 23)]
+    #[case(123, 5, 123)]
+    #[case(u64::MAX, 3, 615)]
+    fn last_digits_returns_the_last_digits(
+        #[case] n: u64,
+        #[case] k: usize,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(last_digits(n, k), expected);
+    }
+
+    #[proptest]
+    fn last_digits_returns_something_n_ends_with(n: u64, k: u8) {
+        let k = usize::from(k % 21);
+        let result = last_digits(n, k);
+        assert!(result <= n);
+        if k > 0 {
+            assert!(n.to_string().ends_with(&result.to_string()[..]));
+        }
+    }
+
+    #[rstest]
+    #[case(0, 13, 0)]
+    #[case(12, 13, 12)]
+    #[case(6468, 13, 7)]
+    #[case(u64::MAX, u64::MAX, 0)]
+    #[case(u64::MAX - 1, u64::MAX, u64::MAX - 1)]
+    fn long_division_remainder_returns_the_remainder(
+        #[case] n: u64,
+        #[case] d: u64,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(long_division_remainder(n, d), expected);
+    }
+
+    #[proptest]
+    fn long_division_remainder_is_bounded(n: u64, d: u64) {
+        if d > 0 {
+            assert!(long_division_remainder(n, d) < d);
+        }
+    }
+
+    #[rstest]
+    #[case(0, 5, 0)]
+    #[case(49, 5, 49)]
+    #[case(434, 5, 21)]
+    #[case(6468, 4, 24)]
+    fn osculate_returns_the_reduced_number(
+        #[case] n: u64,
+        #[case] multiplier: u64,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(osculate(n, multiplier), expected);
+    }
+
+    #[rstest]
+    #[case(0, 5, 0)]
+    #[case(49, 5, 0)]
+    #[case(434, 5, 2)]
+    #[case(6468, 4, 4)]
+    fn osculate_with_steps_counts_the_steps(
+        #[case] n: u64,
+        #[case] multiplier: u64,
+        #[case] expected: u32,
+    ) {
+        assert_eq!(osculate_with_steps(n, multiplier).1, expected);
+    }
+
+    #[proptest]
+    fn osculate_is_bounded(n: u64, multiplier: u8) {
+        let multiplier = u64::from(multiplier % 99) + 1;
+        let result = osculate(n, multiplier);
+        assert!(result <= n);
+        assert!(result < 10 * multiplier);
+    }
+
+    #[rstest]
+    #[case(0, 2, 0)]
+    #[case(19, 2, 19)]
+    #[case(434, 2, 7)]
+    #[case(6468, 9, 21)]
+    #[case(u64::MAX, 0, 1)]
+    fn osculate_backward_returns_the_reduced_number(
+        #[case] n: u64,
+        #[case] multiplier: u64,
+        #[case] expected: u64,
+    ) {
+        assert_eq!(osculate_backward(n, multiplier), expected);
+    }
+
+    #[proptest]
+    fn osculate_backward_is_bounded(n: u64, multiplier: u8) {
+        let multiplier = u64::from(multiplier % 99);
+        let result = osculate_backward(n, multiplier);
+        assert!(result <= n);
+        assert!(result < 10 * multiplier.max(1));
+    }
+
+    #[rstest]
+    #[case(0, 0, 0)]
+    #[case(7, 0, 7)]
+    #[case(42, 4, 2)]
+    #[case(u64::MAX, 1844674407370955161, 5)]
+    fn split_last_digit_returns_the_rest_and_the_last_digit(
+        #[case] n: u64,
+        #[case] rest: u64,
+        #[case] last: u64,
+    ) {
+        assert_eq!(split_last_digit(n), (rest, last));
+    }
+
+    #[proptest]
+    fn split_last_digit_can_be_joined(n: u64) {
+        let (rest, last) = split_last_digit(n);
+        assert_eq!(
+            format!("{rest}{last}").trim_start_matches('0'),
+            n.to_string().trim_start_matches('0')
+        );
+    }
+
+    #[rstest]
+    #[case(3, 1)]
+    #[case(7, 5)]
+    #[case(13, 4)]
+    #[case(97, 68)]
+    #[case(1001, 901)]
+    fn osculator_returns_the_multiplier(#[case] d: u64, #[case] expected: u64) {
+        assert_eq!(osculator(d), expected);
+    }
+
+    #[proptest]
+    fn osculator_is_the_inverse_of_ten(d: u64) {
+        if matches!(last_digit(d.into()), 1 | 3 | 7 | 9) {
+            let result = u128::from(osculator(d));
+            assert_eq!((10 * result) % u128::from(d), 1 % u128::from(d));
+        }
+    }
 }
 
 #[cfg(kani)]
@@ -96,6 +478,22 @@ mod verification {
         assert!(alternating_digit_sum(n.into()).unsigned_abs() < n.into());
     }
 
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_stable_alternating_digit_sum() {
+        let n: u16 = kani::any();
+        let sum = stable_alternating_digit_sum(n.into());
+        assert_eq!(i32::from(n) % 11, (sum as i32).rem_euclid(11));
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_alternating_block_sum() {
+        let n: u16 = kani::any();
+        let sum = alternating_block_sum(n.into(), 3);
+        assert_eq!(i128::from(n) % 1001, sum.rem_euclid(1001));
+    }
+
     #[kani::proof]
     #[kani::unwind(6)]
     pub fn check_digit_sum() {
@@ -106,7 +504,48 @@ mod verification {
 
     #[kani::proof]
     pub fn check_last_digit() {
-        let n: u64 = kani::any();
+        let n: u128 = kani::any();
         assert!(last_digit(n) < 10);
     }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_last_digits() {
+        let n: u16 = kani::any();
+        assert!(last_digits(n.into(), 2) < 100);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_long_division_remainder() {
+        let n: u16 = kani::any();
+        let d: u16 = kani::any();
+        kani::assume(d > 0);
+        assert_eq!(long_division_remainder(n.into(), d.into()), (n % d).into());
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_osculate() {
+        let n: u16 = kani::any();
+        assert_eq!(osculate(n.into(), 5) % 7 == 0, n % 7 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(6)]
+    pub fn check_osculate_backward() {
+        let n: u16 = kani::any();
+        assert_eq!(osculate_backward(n.into(), 2) % 7 == 0, n % 7 == 0);
+    }
+
+    #[kani::proof]
+    #[kani::unwind(10)]
+    pub fn check_osculator() {
+        let d: u16 = kani::any();
+        kani::assume(d % 2 != 0 && d % 5 != 0);
+        assert_eq!(
+            (10 * u32::from(osculator(d.into()))) % u32::from(d),
+            1 % u32::from(d)
+        );
+    }
 }
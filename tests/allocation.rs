@@ -0,0 +1,113 @@
+//! Checks that the rules never allocate, using a global allocator that counts
+//! the allocations made by the current thread.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+
+use divisible_by::{
+    alternating_digit_sum, binary, count_divisible_by, count_multiples, digit_sum, digits,
+    divisible_by, divisible_by_1001_blocks, divisible_by_7_all, divisible_by_all,
+    divisors_up_to_12, gcd_via_rules, is_prime, last_digit, lcm_via_rules, next_multiple, radix,
+    remainder_by, signed, try_divisible_by, DivisibilityState, DivisibleBy,
+};
+
+struct CountingAllocator;
+
+thread_local! {
+    static ALLOCATIONS: Cell<usize> = const { Cell::new(0) };
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.with(|allocations| allocations.set(allocations.get() + 1));
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+fn allocations<T>(f: impl FnOnce() -> T) -> usize {
+    let before = ALLOCATIONS.with(Cell::get);
+    std::hint::black_box(f());
+    ALLOCATIONS.with(Cell::get) - before
+}
+
+#[test]
+fn allocations_are_counted() {
+    assert!(allocations(|| 1001.to_string()) > 0);
+}
+
+const NUMBERS: [u64; 6] = [0, 7, 1001, 6468, 1 << 63, u64::MAX];
+
+#[test]
+fn rules_do_not_allocate() {
+    for n in NUMBERS {
+        let count = allocations(|| {
+            (0..=1000).filter(|&d| divisible_by(n, d)).count()
+                + (1..=1000)
+                    .map(|d| remainder_by(n, d) as usize)
+                    .sum::<usize>()
+        });
+        assert_eq!(count, 0, "the rules allocated for {n}");
+    }
+}
+
+#[test]
+fn integer_rules_do_not_allocate() {
+    for n in NUMBERS {
+        let count = allocations(|| {
+            (
+                u128::from(n).divisible_by_7(),
+                (u128::from(n) << 64).divisible_by_11(),
+                signed::divisible_by(n as i64, 13),
+                binary::divisible_by_17(n),
+                radix::divisible_by(n, 5, 16),
+                radix::digit_sum(n, 16),
+                divisible_by_1001_blocks(n),
+            )
+        });
+        assert_eq!(count, 0, "the integer rules allocated for {n}");
+    }
+}
+
+#[test]
+fn helpers_do_not_allocate() {
+    for n in NUMBERS {
+        let count = allocations(|| {
+            let mut state = DivisibilityState::new();
+            for digit in n.to_le_bytes() {
+                state.push_digit(digit % 10);
+            }
+
+            (
+                state.divisors(),
+                divisors_up_to_12(n),
+                try_divisible_by(n, 0),
+                next_multiple(n, 7),
+                count_multiples(..n, 7),
+                gcd_via_rules(&[n, 1001]),
+                lcm_via_rules(&[n, 12]),
+                is_prime(n),
+                digits(n).rev().max(),
+                (digit_sum(n), alternating_digit_sum(n), last_digit(n)),
+            )
+        });
+        assert_eq!(count, 0, "the helpers allocated for {n}");
+    }
+}
+
+#[test]
+fn batches_do_not_allocate() {
+    let mut out = [false; NUMBERS.len()];
+    let count = allocations(|| {
+        divisible_by_all(&NUMBERS, 7, &mut out);
+        divisible_by_7_all(&NUMBERS, &mut out);
+        count_divisible_by(&NUMBERS, 13)
+    });
+    assert_eq!(count, 0, "the batch functions allocated");
+}